@@ -0,0 +1,308 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! Generates the embedded CEP database of the `cep-db` feature and the IBGE municipality table.
+//!
+//! # CEP database
+//!
+//! The source table is read from the file in the `VALIDBR_CEP_DB_SOURCE` environment variable,
+//! or from `data/cep-sample.csv` when not provided. The table must have a header line followed by
+//! one address per line, with fields separated by `;`:
+//!
+//! ```text
+//! cep;logradouro;bairro;municipio;uf
+//! 01310-100;Avenida Paulista;Bela Vista;São Paulo;SP
+//! ```
+//!
+//! Addresses of the Correios DNE could be exported to this format to generate the complete
+//! database. UFs must be one of the 27 UF abbreviations, and the `VALIDBR_CEP_DB_SAMPLE`
+//! environment variable of the crate tells whether the sample was used.
+//!
+//! The generated database has the following layout, all integers are little endian:
+//!
+//! - Header: `VCEP` magic, version (`u8`), entry count, string count and block count (`u32`).
+//! - String table: `string count + 1` offsets (`u32`) followed by the UTF-8 bytes of every
+//!   distinct string, each string is stored once.
+//! - Block index: first CEP and entries offset (`u32`) of each block of 64 entries.
+//! - Entries, sorted by CEP: CEP delta from the previous entry of the block (the first entry
+//!   of each block is relative to the block first CEP), logradouro, bairro and municipio string
+//!   indexes, all as LEB128 varints, followed by the two UF letters.
+//!
+//! # Municipality table
+//!
+//! The source table is read from the file in the `VALIDBR_MUNICIPIO_SOURCE` environment
+//! variable, or from `data/municipios.csv` when not provided. The table must have a header line
+//! followed by the complete IBGE code and the name of one municipality per line, which are the
+//! `Código Município Completo` and `Nome_Município` columns of the IBGE DTB (Divisão Territorial
+//! Brasileira), separated by `;`:
+//!
+//! ```text
+//! codigo;nome
+//! 3550308;São Paulo
+//! ```
+//!
+//! `data/municipios.csv` holds the 5,571 municipalities published by the IBGE Localidades API
+//! (`servicodados.ibge.gov.br/api/v1/localidades/municipios`) as of September 2026. Sources with
+//! less than [`MIN_MUNICIPIOS`] rows are rejected, as they could not be a complete DTB.
+//!
+//! The UF of each municipality is resolved from the first two digits of its code, and the
+//! capitals from [`CAPITALS`]. The table is generated as `municipios.rs`, an array expression of
+//! `Municipio` sorted by code.
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const BLOCK_SIZE: usize = 64;
+
+/// UFs by their IBGE code, the first two digits of the IBGE codes of their municipalities.
+const UF_CODES: [(u32, &str); 27] = [
+    (11, "RO"), (12, "AC"), (13, "AM"), (14, "RR"), (15, "PA"), (16, "AP"), (17, "TO"),
+    (21, "MA"), (22, "PI"), (23, "CE"), (24, "RN"), (25, "PB"), (26, "PE"), (27, "AL"),
+    (28, "SE"), (29, "BA"), (31, "MG"), (32, "ES"), (33, "RJ"), (35, "SP"), (41, "PR"),
+    (42, "SC"), (43, "RS"), (50, "MS"), (51, "MT"), (52, "GO"), (53, "DF"),
+];
+
+/// Minimum amount of municipalities of a complete DTB, which had 5,571 municipalities in 2026.
+const MIN_MUNICIPIOS: usize = 5_500;
+
+/// IBGE codes of the capitals of every UF, since the DTB does not flag capitals.
+const CAPITALS: [u32; 27] = [
+    1100205, 1200401, 1302603, 1400100, 1501402, 1600303, 1721000, 2111300, 2211001, 2304400,
+    2408102, 2507507, 2611606, 2704302, 2800308, 2927408, 3106200, 3205309, 3304557, 3550308,
+    4106902, 4205407, 4314902, 5002704, 5103403, 5208707, 5300108,
+];
+
+struct Entry {
+    logradouro: u32,
+    bairro: u32,
+    municipio: u32,
+    uf: [u8; 2],
+}
+
+#[derive(Default)]
+struct Strings {
+    indexes: HashMap<String, u32>,
+    values: Vec<String>,
+}
+
+impl Strings {
+    fn index(&mut self, value: &str) -> u32 {
+        if let Some(index) = self.indexes.get(value) {
+            return *index
+        }
+
+        let index = self.values.len() as u32;
+        self.indexes.insert(value.to_string(), index);
+        self.values.push(value.to_string());
+        index
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn parse_cep(cep: &str, line: usize) -> u32 {
+    let digits: String = cep.chars().filter(|c| *c != '-').collect();
+    if digits.len() != 8 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        panic!("invalid CEP `{}` at line {} of the CEP database source", cep, line)
+    }
+    digits.parse().expect("CEP digits MUST be a number at this point")
+}
+
+fn out_dir() -> PathBuf {
+    PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR MUST be set by cargo"))
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=VALIDBR_CEP_DB_SOURCE");
+    println!("cargo:rerun-if-env-changed=VALIDBR_MUNICIPIO_SOURCE");
+
+    generate_municipios();
+    if env::var_os("CARGO_FEATURE_CEP_DB").is_some() {
+        generate_cep_db();
+    }
+}
+
+fn generate_municipios() {
+    let source = env::var_os("VALIDBR_MUNICIPIO_SOURCE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("data/municipios.csv"));
+    println!("cargo:rerun-if-changed={}", source.display());
+
+    let table = fs::read_to_string(&source)
+        .unwrap_or_else(|e| panic!("could not read municipality table source {}: {}", source.display(), e));
+
+    let mut municipios: BTreeMap<u32, (String, &str)> = BTreeMap::new();
+    for (number, line) in table.lines().enumerate().skip(1) {
+        let line_number = number + 1;
+        if line.trim().is_empty() {
+            continue
+        }
+
+        let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        if fields.len() != 2 {
+            panic!("expected 2 fields at line {} of the municipality table source, found {}", line_number, fields.len())
+        }
+
+        let code: u32 = fields[0]
+            .parse()
+            .ok()
+            .filter(|code| (1_000_000..10_000_000).contains(code))
+            .unwrap_or_else(|| panic!("invalid IBGE code `{}` at line {} of the municipality table source", fields[0], line_number));
+        let uf = UF_CODES
+            .iter()
+            .find(|(uf_code, _)| *uf_code == code / 100_000)
+            .map(|(_, uf)| *uf)
+            .unwrap_or_else(|| panic!("unknown UF of IBGE code `{}` at line {} of the municipality table source", code, line_number));
+
+        if municipios.insert(code, (fields[1].to_string(), uf)).is_some() {
+            panic!("duplicated IBGE code `{}` at line {} of the municipality table source", code, line_number)
+        }
+    }
+
+    if municipios.len() < MIN_MUNICIPIOS {
+        panic!(
+            "expected at least {} municipalities in the municipality table source, found {}",
+            MIN_MUNICIPIOS,
+            municipios.len()
+        )
+    }
+
+    for capital in CAPITALS.iter() {
+        if !municipios.contains_key(capital) {
+            panic!("capital `{}` is missing from the municipality table source", capital)
+        }
+    }
+
+    let mut generated = String::from("[\n");
+    for (code, (name, uf)) in &municipios {
+        generated.push_str(&format!(
+            "    Municipio {{ code: {}, name: {:?}, uf: UF::{}, capital: {} }},\n",
+            code,
+            name,
+            uf,
+            CAPITALS.contains(code)
+        ));
+    }
+    generated.push(']');
+
+    fs::write(out_dir().join("municipios.rs"), generated).expect("could not write the municipality table");
+}
+
+fn generate_cep_db() {
+    let custom_source = env::var_os("VALIDBR_CEP_DB_SOURCE");
+    println!("cargo:rustc-env=VALIDBR_CEP_DB_SAMPLE={}", custom_source.is_none());
+    let source = custom_source
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("data/cep-sample.csv"));
+    println!("cargo:rerun-if-changed={}", source.display());
+
+    let table = fs::read_to_string(&source)
+        .unwrap_or_else(|e| panic!("could not read CEP database source {}: {}", source.display(), e));
+
+    let mut strings = Strings::default();
+    let mut entries: BTreeMap<u32, Entry> = BTreeMap::new();
+
+    for (number, line) in table.lines().enumerate().skip(1) {
+        let line_number = number + 1;
+        if line.trim().is_empty() {
+            continue
+        }
+
+        let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        if fields.len() != 5 {
+            panic!("expected 5 fields at line {} of the CEP database source, found {}", line_number, fields.len())
+        }
+
+        let uf: [u8; 2] = UF_CODES
+            .iter()
+            .find(|(_, uf)| fields[4].eq_ignore_ascii_case(uf))
+            .and_then(|(_, uf)| uf.as_bytes().try_into().ok())
+            .unwrap_or_else(|| panic!("invalid UF `{}` at line {} of the CEP database source", fields[4], line_number));
+
+        entries.insert(parse_cep(fields[0], line_number), Entry {
+            logradouro: strings.index(fields[1]),
+            bairro: strings.index(fields[2]),
+            municipio: strings.index(fields[3]),
+            uf,
+        });
+    }
+
+    let entries: Vec<(u32, Entry)> = entries.into_iter().collect();
+    let blocks: Vec<&[(u32, Entry)]> = entries.chunks(BLOCK_SIZE).collect();
+
+    let mut entries_section = Vec::new();
+    let mut block_index = Vec::new();
+    for block in &blocks {
+        let first_cep = block[0].0;
+        block_index.extend_from_slice(&first_cep.to_le_bytes());
+        block_index.extend_from_slice(&(entries_section.len() as u32).to_le_bytes());
+
+        let mut previous = first_cep;
+        for (cep, entry) in block.iter() {
+            write_varint(&mut entries_section, cep - previous);
+            write_varint(&mut entries_section, entry.logradouro);
+            write_varint(&mut entries_section, entry.bairro);
+            write_varint(&mut entries_section, entry.municipio);
+            entries_section.extend_from_slice(&entry.uf);
+            previous = *cep;
+        }
+    }
+
+    let mut database = Vec::new();
+    database.extend_from_slice(b"VCEP");
+    database.push(1);
+    database.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+    database.extend_from_slice(&(strings.values.len() as u32).to_le_bytes());
+    database.extend_from_slice(&(blocks.len() as u32).to_le_bytes());
+
+    let mut offset = 0u32;
+    database.extend_from_slice(&offset.to_le_bytes());
+    for value in &strings.values {
+        offset += value.len() as u32;
+        database.extend_from_slice(&offset.to_le_bytes());
+    }
+    for value in &strings.values {
+        database.extend_from_slice(value.as_bytes());
+    }
+
+    database.extend_from_slice(&block_index);
+    database.extend_from_slice(&entries_section);
+
+    fs::write(out_dir().join("cep.db"), database).expect("could not write the CEP database");
+}
@@ -22,46 +22,61 @@
 //      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
 //      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 //      THE SOFTWARE.
-use std::convert::TryInto;
 
+/// Appends elements to arrays, producing a new array with the appended elements.
+///
+/// The length `R` of the resulting array must be provided (or inferred) by the caller, and must be
+/// the sum of the lengths of both parts, which is checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// use validbr::append::ArrayAppend;
+///
+/// let digits: [u8; 4] = [1, 2, 3].append(4);
+/// assert_eq!(digits, [1, 2, 3, 4]);
+///
+/// let digits: [u8; 5] = [1, 2, 3].append_array([4, 5]);
+/// assert_eq!(digits, [1, 2, 3, 4, 5]);
+/// ```
+///
+/// ```compile_fail
+/// use validbr::append::ArrayAppend;
+///
+/// let digits: [u8; 5] = [1, 2, 3].append(4);
+/// ```
 pub trait ArrayAppend<T, const S: usize> {
-    fn append(self, element: T) -> [T; S + 1];
-    fn append_array<const N: usize>(self, array: [T; N]) -> [T; S + N];
+    /// Appends `element` to the end of this array, `R` must be `S + 1`.
+    fn append<const R: usize>(self, element: T) -> [T; R];
+    /// Appends all elements of `array` to the end of this array, `R` must be `S + N`.
+    fn append_array<const N: usize, const R: usize>(self, array: [T; N]) -> [T; R];
 }
 
 impl<T, const S: usize> ArrayAppend<T, S> for [T; S]
 where
     T: Clone,
 {
-    fn append(self, element: T) -> [T; S + 1] {
-        let vec: Vec<T> = self
-            .to_vec()
-            .iter()
-            .map(|i| i.clone())
-            .chain(std::iter::once(element))
-            .collect();
+    fn append<const R: usize>(self, element: T) -> [T; R] {
+        const { assert!(R == S + 1, "Resulting array length must be the array length plus one.") };
 
-        let n_array: Result<[T; S + 1], _> = vec.try_into();
-        if let Ok(n_array) = n_array {
-            n_array
-        } else {
-            panic!();
-        }
+        core::array::from_fn(|i| {
+            if i < S {
+                self[i].clone()
+            } else {
+                element.clone()
+            }
+        })
     }
 
-    fn append_array<const N: usize>(self, array: [T; N]) -> [T; S + N] {
-        let vec: Vec<T> = self
-            .to_vec()
-            .iter()
-            .map(|i| i.clone())
-            .chain(array.iter().map(|i| i.clone()))
-            .collect();
+    fn append_array<const N: usize, const R: usize>(self, array: [T; N]) -> [T; R] {
+        const { assert!(R == S + N, "Resulting array length must be the sum of both array lengths.") };
 
-        let n_array: Result<[T; S + N], _> = vec.try_into();
-        if let Ok(n_array) = n_array {
-            n_array
-        } else {
-            panic!();
-        }
+        core::array::from_fn(|i| {
+            if i < S {
+                self[i].clone()
+            } else {
+                array[i - S].clone()
+            }
+        })
     }
 }
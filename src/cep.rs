@@ -0,0 +1,346 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # CEP
+//!
+//! This module provides utility for constructing CEPs and resolving the [`UF`] a CEP belongs
+//! to, through the CEP ranges assigned by Correios to each UF.
+#[cfg(feature = "cep-db")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cep-db")))]
+pub mod db;
+
+use crate::{Cep, UF};
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError};
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+use core::fmt::Formatter;
+
+/// Mask of the well known Cep format, see [`parse::normalize`].
+const CEP_MASK: &str = "#####-###";
+
+/// Inclusive CEP ranges assigned by Correios to each UF, as 8 digits numbers.
+///
+/// Some UFs have more than one range, and some ranges are not assigned to any UF, such as
+/// `00000-000..=00999-999`.
+const CEP_RANGES: [(u32, u32, UF); 30] = [
+    (1_000_000, 19_999_999, UF::SP),
+    (20_000_000, 28_999_999, UF::RJ),
+    (29_000_000, 29_999_999, UF::ES),
+    (30_000_000, 39_999_999, UF::MG),
+    (40_000_000, 48_999_999, UF::BA),
+    (49_000_000, 49_999_999, UF::SE),
+    (50_000_000, 56_999_999, UF::PE),
+    (57_000_000, 57_999_999, UF::AL),
+    (58_000_000, 58_999_999, UF::PB),
+    (59_000_000, 59_999_999, UF::RN),
+    (60_000_000, 63_999_999, UF::CE),
+    (64_000_000, 64_999_999, UF::PI),
+    (65_000_000, 65_999_999, UF::MA),
+    (66_000_000, 68_899_999, UF::PA),
+    (68_900_000, 68_999_999, UF::AP),
+    (69_000_000, 69_299_999, UF::AM),
+    (69_300_000, 69_399_999, UF::RR),
+    (69_400_000, 69_899_999, UF::AM),
+    (69_900_000, 69_999_999, UF::AC),
+    (70_000_000, 72_799_999, UF::DF),
+    (72_800_000, 72_999_999, UF::GO),
+    (73_000_000, 73_699_999, UF::DF),
+    (73_700_000, 76_799_999, UF::GO),
+    (76_800_000, 76_999_999, UF::RO),
+    (77_000_000, 77_999_999, UF::TO),
+    (78_000_000, 78_899_999, UF::MT),
+    (79_000_000, 79_999_999, UF::MS),
+    (80_000_000, 87_999_999, UF::PR),
+    (88_000_000, 89_999_999, UF::SC),
+    (90_000_000, 99_999_999, UF::RS),
+];
+
+/// Formats Cep in the well known format:
+/// 00000-000
+/// # Example
+///
+/// ```
+/// use validbr::Cep;
+/// let cep = Cep::parse_str("01310100").expect("Invalid cep.");
+/// assert_eq!(format!("{}", cep), "01310-100")
+/// ```
+impl fmt::Display for Cep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let d = &self.digits;
+        write!(
+            f,
+            "{}{}{}{}{}-{}{}{}",
+            d[0], d[1], d[2], d[3], d[4], d[5], d[6], d[7]
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum CepCreationError {
+    /// When provided Cep string is not a valid Cep format.
+    ///
+    /// Supported Cep formats are:
+    /// - 00000-000
+    /// - 00000000
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidCepStringFormat(FormatMismatch),
+    /// When provided Cep string has less digits than a Cep.
+    ShortCepString(FormatMismatch),
+    /// When provided numbers for digits are out of bounds, in other words, they are not
+    /// respecting the range of `0..=9`.
+    DigitsOutOfBounds,
+    /// When provided number has more than 8 digits, thus could not be converted to a Cep.
+    NumberOutOfRange,
+}
+
+impl LocalizedError for CepCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            CepCreationError::InvalidCepStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid CEP format, expected #####-### or ########",
+                "formato de CEP inválido, esperado #####-### ou ########",
+                mismatch,
+            ),
+            CepCreationError::ShortCepString(mismatch) => write_with_details(
+                f,
+                language,
+                "CEP has less than 8 digits",
+                "CEP possui menos de 8 dígitos",
+                mismatch,
+            ),
+            CepCreationError::DigitsOutOfBounds => write_message(
+                f,
+                language,
+                "CEP digits must be in the range of 0 to 9",
+                "os dígitos do CEP devem estar entre 0 e 9",
+            ),
+            CepCreationError::NumberOutOfRange => write_message(
+                f,
+                language,
+                "number has more than 8 digits and could not be converted to a CEP",
+                "o número possui mais de 8 dígitos e não pode ser convertido em CEP",
+            ),
+        }
+    }
+}
+
+impl_error!(CepCreationError);
+
+impl Cep {
+    /// Creates a new Cep if all the provided `[digits]` are in the range of `0..=9`.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Cep;
+    /// use validbr::cep::CepCreationError;
+    ///
+    /// assert!(Cep::new([0, 1, 3, 1, 0, 1, 0, 0]).is_ok());
+    /// assert_eq!(Cep::new([0, 1, 3, 1, 0, 1, 0, 10]), Err(CepCreationError::DigitsOutOfBounds));
+    /// ```
+    pub fn new(digits: [u8; 8]) -> Result<Cep, CepCreationError> {
+        if digits.iter().all(|d| *d <= 9) {
+            Ok(Cep { digits })
+        } else {
+            Err(CepCreationError::DigitsOutOfBounds)
+        }
+    }
+
+    /// Parses a Cep String to a [`Cep`].
+    ///
+    /// Supported formats are:
+    /// - `#####-###` (Commonly represented CEP)
+    /// - `########` (Only digits CEP)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cep;
+    /// let cep = Cep::parse_str("01310-100");
+    /// assert_eq!(cep, Ok(Cep { digits: [0, 1, 3, 1, 0, 1, 0, 0] }));
+    /// assert_eq!(Cep::parse_str("01310100"), cep);
+    /// ```
+    pub fn parse_str(cep: &str) -> Result<Cep, CepCreationError> {
+        Cep::parse_str_with(cep, &ParseOptions::strict())
+    }
+
+    /// Parses a Cep String to a [`Cep`] according to the provided parse `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cep;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let cep = Cep::parse_str_with("1310100", &ParseOptions::padded());
+    /// assert_eq!(cep, Ok(Cep { digits: [0, 1, 3, 1, 0, 1, 0, 0] }));
+    /// ```
+    pub fn parse_str_with(cep: &str, options: &ParseOptions) -> Result<Cep, CepCreationError> {
+        let cep_only_with_numbers: [u8; 8] = parse::normalize(cep, options, CEP_MASK)
+            .map_err(|e| match e {
+                FormatError::InvalidFormat(mismatch) => CepCreationError::InvalidCepStringFormat(mismatch),
+                FormatError::Short(mismatch) => CepCreationError::ShortCepString(mismatch),
+            })?;
+
+        Cep::new(core::array::from_fn(|i| cep_only_with_numbers[i] - b'0'))
+    }
+
+    /// Region of this Cep, the first digit, which groups neighbour UFs.
+    pub fn region(&self) -> u8 {
+        self.digits[0]
+    }
+
+    /// Sub-region of this Cep, the second digit, which subdivides the region.
+    pub fn sub_region(&self) -> u8 {
+        self.digits[1]
+    }
+
+    /// Sector of this Cep, the third digit, which subdivides the sub-region.
+    pub fn sector(&self) -> u8 {
+        self.digits[2]
+    }
+
+    /// Sub-sector of this Cep, the fourth digit, which subdivides the sector.
+    pub fn sub_sector(&self) -> u8 {
+        self.digits[3]
+    }
+
+    /// Sector divisor of this Cep, the fifth digit, which subdivides the sub-sector.
+    pub fn sector_divisor(&self) -> u8 {
+        self.digits[4]
+    }
+
+    /// Suffix of this Cep, the last three digits, which identify the addresses in the sector
+    /// divisor.
+    pub fn suffix(&self) -> [u8; 3] {
+        [self.digits[5], self.digits[6], self.digits[7]]
+    }
+
+    /// Resolves the [`UF`] this Cep belongs to, through the CEP ranges assigned by Correios to each
+    /// UF, or [`None`] if the Cep is not in any of these ranges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{Cep, UF};
+    ///
+    /// assert_eq!(Cep::parse_str("01310-100").unwrap().uf(), Some(UF::SP));
+    /// assert_eq!(Cep::parse_str("69301-000").unwrap().uf(), Some(UF::RR));
+    /// assert_eq!(Cep::parse_str("00100-000").unwrap().uf(), None);
+    /// ```
+    pub fn uf(&self) -> Option<UF> {
+        let number = u32::from(self);
+        CEP_RANGES
+            .iter()
+            .find(|(start, end, _)| (*start..=*end).contains(&number))
+            .map(|(_, _, uf)| *uf)
+    }
+
+    /// Checks whether this Cep belongs to the provided `uf`, see [`Cep::uf`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{Cep, UF};
+    ///
+    /// let cep = Cep::parse_str("70040-010").unwrap();
+    /// assert!(cep.belongs_to(&UF::DF));
+    /// assert!(!cep.belongs_to(&UF::GO));
+    /// ```
+    pub fn belongs_to(&self, uf: &UF) -> bool {
+        self.uf().as_ref() == Some(uf)
+    }
+}
+
+/// Parses a Cep String to a [`Cep`], see [`Cep::parse_str`] for supported formats.
+impl FromStr for Cep {
+    type Err = CepCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cep::parse_str(s)
+    }
+}
+
+/// Parses a Cep String to a [`Cep`], see [`Cep::parse_str`] for supported formats.
+impl TryFrom<&str> for Cep {
+    type Error = CepCreationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Cep::parse_str(value)
+    }
+}
+
+/// Converts a number to a [`Cep`], the number is padded with leading zeros, so numbers stored
+/// without the leading zeros of the Cep are supported.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cep;
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(Cep::try_from(1310100u32), Cep::parse_str("01310-100"));
+/// assert!(Cep::try_from(100_000_000u32).is_err());
+/// ```
+impl TryFrom<u32> for Cep {
+    type Error = CepCreationError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value > 99_999_999 {
+            return Err(CepCreationError::NumberOutOfRange)
+        }
+
+        let mut digits = [0u8; 8];
+        let mut remaining = value;
+        for digit in digits.iter_mut().rev() {
+            *digit = (remaining % 10) as u8;
+            remaining /= 10;
+        }
+
+        Cep::new(digits)
+    }
+}
+
+/// Converts a [`Cep`] to its number representation, the leading zeros of the Cep are lost in
+/// this representation.
+impl From<&Cep> for u32 {
+    fn from(cep: &Cep) -> Self {
+        cep.digits.iter().fold(0u32, |acc, d| acc * 10 + (*d as u32))
+    }
+}
+
+/// Converts a [`Cep`] to its number representation, the leading zeros of the Cep are lost in
+/// this representation.
+impl From<Cep> for u32 {
+    fn from(cep: Cep) -> Self {
+        u32::from(&cep)
+    }
+}
@@ -0,0 +1,416 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # CEP database
+//!
+//! Offline CEP database embedded in the crate through the `cep-db` feature, which resolves a
+//! [`Cep`] to its [`Address`] without any external service.
+//!
+//! The database is generated by the build script from a `;` separated table, by default the
+//! small sample in `data/cep-sample.csv`, which only covers a few addresses for testing purposes.
+//! Set the `VALIDBR_CEP_DB_SOURCE` environment variable to a table exported from the Correios DNE
+//! to embed the complete database, see `build.rs` for the table format.
+//!
+//! The sample does not cover almost every existing Cep, also when the `cep-db` feature is
+//! enabled through the `complete` feature, thus Ceps missing from the sample are reported as
+//! [`CepLookupError::NotCovered`] rather than [`CepLookupError::NotFound`], which is only
+//! reported by a complete database.
+//!
+//! Strings are stored once and entries are delta encoded in blocks, the database is embedded as
+//! static bytes and only the block of a looked up Cep is decoded, thus lookups do not allocate
+//! nor need any initialization.
+//!
+//! # Example
+//!
+//! ```
+//! use validbr::{Cep, UF};
+//!
+//! let address = Cep::parse_str("01310-100").unwrap().lookup().unwrap();
+//! assert_eq!(address.municipio, "São Paulo");
+//! assert_eq!(address.uf, UF::SP);
+//! ```
+use crate::{Cep, UF};
+use crate::cep::CepCreationError;
+use crate::error::{impl_error, write_message, FormatMismatch, Language, LocalizedError};
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Formatter;
+
+static DATABASE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/cep.db"));
+
+/// Amount of entries in each block of the database, must match the build script.
+const BLOCK_SIZE: usize = 64;
+
+/// Length of the database header: magic, version and three counts.
+const HEADER_LENGTH: usize = 4 + 1 + 4 * 3;
+
+/// Address of a Cep in the CEP database.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Address {
+    /// Cep of the address.
+    pub cep: Cep,
+    /// Street, avenue, square or any other public place, may be empty for Ceps which cover an
+    /// entire municipio.
+    pub logradouro: &'static str,
+    /// Neighbourhood, may be empty for Ceps which cover an entire municipio.
+    pub bairro: &'static str,
+    /// City.
+    pub municipio: &'static str,
+    /// UF of the municipio.
+    pub uf: UF,
+}
+
+/// Formats the address as it is usually written in Brazil, for example:
+/// `Avenida Paulista, Bela Vista, São Paulo - SP, 01310-100`.
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for part in [self.logradouro, self.bairro].iter().filter(|part| !part.is_empty()) {
+            write!(f, "{}, ", part)?;
+        }
+        write!(f, "{} - {}, {}", self.municipio, self.uf, self.cep)
+    }
+}
+
+/// Errors of the lookups in the CEP database.
+#[derive(Debug, Eq, PartialEq)]
+pub enum CepLookupError {
+    /// When the Cep, or any Cep starting with the searched prefix, is not in the complete
+    /// database, thus it does not exist.
+    NotFound,
+    /// When the embedded database is the sample, see [`is_sample`], and it does not have the Cep,
+    /// or any Cep starting with the searched prefix, which may exist nonetheless.
+    NotCovered,
+    /// When the searched prefix is not the beginning of a Cep in the well known format.
+    InvalidPrefix(CepCreationError),
+}
+
+impl LocalizedError for CepLookupError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            CepLookupError::NotFound => write_message(
+                f,
+                language,
+                "CEP not found in the CEP database",
+                "CEP não encontrado na base de CEPs",
+            ),
+            CepLookupError::NotCovered => write_message(
+                f,
+                language,
+                "CEP not covered by the sample CEP database",
+                "CEP não coberto pela amostra da base de CEPs",
+            ),
+            CepLookupError::InvalidPrefix(error) => error.fmt_localized(f, language),
+        }
+    }
+}
+
+impl_error!(CepLookupError);
+
+/// Error of a Cep missing from the embedded database.
+fn missing() -> CepLookupError {
+    if is_sample() {
+        CepLookupError::NotCovered
+    } else {
+        CepLookupError::NotFound
+    }
+}
+
+/// View over the embedded database bytes.
+#[derive(Clone, Copy)]
+struct Database {
+    bytes: &'static [u8],
+    entry_count: usize,
+    string_count: usize,
+    block_count: usize,
+}
+
+impl Database {
+    fn embedded() -> Database {
+        let bytes = DATABASE;
+        assert_eq!(&bytes[..5], b"VCEP\x01", "Embedded CEP database MUST be generated by the build script");
+
+        Database {
+            bytes,
+            entry_count: read_u32(bytes, 5) as usize,
+            string_count: read_u32(bytes, 9) as usize,
+            block_count: read_u32(bytes, 13) as usize,
+        }
+    }
+
+    fn string_data_offset(&self) -> usize {
+        HEADER_LENGTH + (self.string_count + 1) * 4
+    }
+
+    fn blocks_offset(&self) -> usize {
+        self.string_data_offset() + read_u32(self.bytes, HEADER_LENGTH + self.string_count * 4) as usize
+    }
+
+    fn entries_offset(&self) -> usize {
+        self.blocks_offset() + self.block_count * 8
+    }
+
+    fn string(&self, index: u32) -> &'static str {
+        let index = index as usize;
+        let start = read_u32(self.bytes, HEADER_LENGTH + index * 4) as usize;
+        let end = read_u32(self.bytes, HEADER_LENGTH + (index + 1) * 4) as usize;
+        let data = self.string_data_offset();
+
+        core::str::from_utf8(&self.bytes[data + start..data + end])
+            .expect("Strings of the CEP database MUST be valid UTF-8")
+    }
+
+    /// First Cep number and entries offset of the `block`.
+    fn block(&self, block: usize) -> (u32, usize) {
+        let offset = self.blocks_offset() + block * 8;
+        (read_u32(self.bytes, offset), read_u32(self.bytes, offset + 4) as usize)
+    }
+
+    /// Cursor at the first entry of the block which could contain `cep`.
+    fn cursor(&self, cep: u32) -> Cursor {
+        let mut low = 0;
+        let mut high = self.block_count;
+        while low < high {
+            let middle = (low + high) / 2;
+            if self.block(middle).0 <= cep {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        Cursor {
+            database: *self,
+            entry: low.saturating_sub(1) * BLOCK_SIZE,
+            offset: 0,
+            previous: 0,
+        }
+    }
+}
+
+/// Sequential decoder of the database entries.
+#[derive(Clone)]
+struct Cursor {
+    database: Database,
+    entry: usize,
+    offset: usize,
+    previous: u32,
+}
+
+impl Iterator for Cursor {
+    type Item = Address;
+
+    fn next(&mut self) -> Option<Address> {
+        let database = &self.database;
+        if self.entry >= database.entry_count {
+            return None
+        }
+
+        if self.entry % BLOCK_SIZE == 0 {
+            let (first_cep, offset) = database.block(self.entry / BLOCK_SIZE);
+            self.previous = first_cep;
+            self.offset = database.entries_offset() + offset;
+        }
+
+        let bytes = database.bytes;
+        let cep = self.previous + read_varint(bytes, &mut self.offset);
+        let logradouro = read_varint(bytes, &mut self.offset);
+        let bairro = read_varint(bytes, &mut self.offset);
+        let municipio = read_varint(bytes, &mut self.offset);
+        let uf = core::str::from_utf8(&bytes[self.offset..self.offset + 2])
+            .ok()
+            .and_then(|uf| uf.parse().ok())
+            .expect("UFs of the CEP database MUST be valid");
+        self.offset += 2;
+
+        self.previous = cep;
+        self.entry += 1;
+
+        Some(Address {
+            cep: Cep::try_from(cep).expect("Ceps of the CEP database MUST be valid"),
+            logradouro: database.string(logradouro),
+            bairro: database.string(bairro),
+            municipio: database.string(municipio),
+            uf,
+        })
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+}
+
+fn read_varint(bytes: &[u8], offset: &mut usize) -> u32 {
+    let mut value = 0u32;
+    let mut shift = 0;
+    loop {
+        let byte = bytes[*offset];
+        *offset += 1;
+        value |= ((byte & 0x7F) as u32) << shift;
+        if byte & 0x80 == 0 {
+            return value
+        }
+        shift += 7;
+    }
+}
+
+/// Whether the embedded database is the sample bundled in the crate, which only covers a few
+/// Ceps, rather than a table provided through `VALIDBR_CEP_DB_SOURCE`.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cep;
+/// use validbr::cep::db::is_sample;
+///
+/// if is_sample() {
+///     println!("Set VALIDBR_CEP_DB_SOURCE to embed the complete CEP database");
+/// }
+/// ```
+pub fn is_sample() -> bool {
+    matches!(env!("VALIDBR_CEP_DB_SAMPLE").as_bytes(), b"true")
+}
+
+impl Cep {
+    /// Looks up the [`Address`] of this Cep in the embedded CEP database.
+    ///
+    /// Unless the crate was built with `VALIDBR_CEP_DB_SOURCE`, the database is only a sample of a
+    /// few Ceps, and Ceps missing from it are reported as [`CepLookupError::NotCovered`], as they
+    /// may exist nonetheless. Ceps missing from a complete database are reported as
+    /// [`CepLookupError::NotFound`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cep;
+    /// use validbr::cep::db::{is_sample, CepLookupError};
+    ///
+    /// let cep = Cep::parse_str("01001-000").unwrap();
+    /// let address = cep.lookup().unwrap();
+    /// assert_eq!(address.cep, cep);
+    /// assert!(address.to_string().ends_with("São Paulo - SP, 01001-000"));
+    ///
+    /// let missing = Cep::parse_str("00000-001").unwrap().lookup();
+    /// if is_sample() {
+    ///     assert_eq!(missing, Err(CepLookupError::NotCovered));
+    /// } else {
+    ///     assert_eq!(missing, Err(CepLookupError::NotFound));
+    /// }
+    /// ```
+    pub fn lookup(&self) -> Result<Address, CepLookupError> {
+        let cep = u32::from(self);
+        Database::embedded()
+            .cursor(cep)
+            .find(|address| u32::from(&address.cep) >= cep)
+            .filter(|address| address.cep == *self)
+            .ok_or_else(missing)
+    }
+}
+
+/// Iterator over the addresses of the Ceps starting with a prefix, sorted by Cep, see
+/// [`search_prefix`].
+#[derive(Clone)]
+pub struct PrefixSearch {
+    cursor: Cursor,
+    start: u32,
+    end: u32,
+}
+
+impl Iterator for PrefixSearch {
+    type Item = Address;
+
+    fn next(&mut self) -> Option<Address> {
+        loop {
+            let address = self.cursor.next()?;
+            let cep = u32::from(&address.cep);
+            if cep > self.end {
+                return None
+            }
+            if cep >= self.start {
+                return Some(address)
+            }
+        }
+    }
+}
+
+/// Searches the addresses of the Ceps starting with `prefix` in the embedded CEP database, which
+/// is useful for autocompletion.
+///
+/// The `prefix` must have up to 8 digits and may contain a single `-` separator after the fifth
+/// digit, as in the well known Cep format, otherwise [`CepLookupError::InvalidPrefix`] is
+/// returned. A complete database finds no address when no Cep starts with `prefix`, while the
+/// sample database, see [`is_sample`], reports [`CepLookupError::NotCovered`], as such Ceps may
+/// exist nonetheless. The addresses found in the sample are only the few ones it has.
+///
+/// # Example
+///
+/// ```
+/// use validbr::cep::db::{search_prefix, CepLookupError};
+///
+/// let ceps: Vec<String> = search_prefix("01310").unwrap().map(|a| a.cep.to_string()).collect();
+/// assert!(ceps.contains(&"01310-100".to_string()));
+/// assert!(ceps.iter().all(|cep| cep.starts_with("01310-")));
+/// assert!(search_prefix("01310-1").unwrap().all(|a| a.cep.to_string().starts_with("01310-1")));
+/// assert!(matches!(search_prefix("0131x"), Err(CepLookupError::InvalidPrefix(_))));
+/// assert!(matches!(search_prefix("01310--"), Err(CepLookupError::InvalidPrefix(_))));
+/// ```
+pub fn search_prefix(prefix: &str) -> Result<PrefixSearch, CepLookupError> {
+    let mismatch = |position: usize, found: Option<char>| FormatMismatch {
+        position,
+        found,
+        input_length: prefix.len(),
+    };
+
+    let mut value = 0u32;
+    let mut digits = 0u32;
+    let mut separated = false;
+    for (position, c) in prefix.char_indices() {
+        match c.to_digit(10) {
+            Some(digit) if digits < 8 => {
+                value = value * 10 + digit;
+                digits += 1;
+            }
+            _ if c == '-' && digits == 5 && !separated => separated = true,
+            _ => {
+                let error = CepCreationError::InvalidCepStringFormat(mismatch(position, Some(c)));
+                return Err(CepLookupError::InvalidPrefix(error))
+            }
+        }
+    }
+
+    let scale = 10u32.pow(8 - digits);
+    let start = value * scale;
+    let end = start + (scale - 1);
+
+    let search = PrefixSearch {
+        cursor: Database::embedded().cursor(start),
+        start,
+        end,
+    };
+
+    if is_sample() && search.clone().next().is_none() {
+        return Err(CepLookupError::NotCovered)
+    }
+    Ok(search)
+}
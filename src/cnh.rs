@@ -0,0 +1,743 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # CNH
+//!
+//! This module provides utility for constructing and validating CNH (Carteira Nacional de
+//! Habilitação) registry numbers, as well as the structured information of a driver's license,
+//! see [`CnhDetails`](crate::cnh::CnhDetails). If a CNH was successfully constructed with [`Cnh::new`] or
+//! [`Cnh::parse_str`] it means that the CNH registry number is valid.
+use crate::Cnh;
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError, VerifierDigitsMismatch};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+use core::fmt::Formatter;
+#[cfg(feature = "rand")]
+use {
+    rand::distributions::{Distribution, Standard, Uniform},
+    rand::Rng,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Mask of the Cnh format, see [`parse::normalize`].
+const CNH_MASK: &str = "###########";
+
+/// Formats Cnh as its 11 digits, which is how the registry number is printed in the driver's
+/// license.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cnh;
+/// let cnh = Cnh::parse_str("02978515070").expect("Invalid cnh.");
+/// assert_eq!(format!("{}", cnh), "02978515070")
+/// ```
+impl fmt::Display for Cnh {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for digit in self.digits.iter().chain(self.verifier_digits.iter()) {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum CnhCreationError {
+    /// When provided Cnh digits could not be validated against their verifier digits by any of
+    /// the algorithms, in other words, when provided Cnh is not valid.
+    ///
+    /// Holds the verifier digits expected by the current algorithm and the provided ones.
+    InvalidCnhDigits(VerifierDigitsMismatch<2>),
+    /// When provided Cnh string is not a valid Cnh format, which is 11 digits.
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidCnhStringFormat(FormatMismatch),
+    /// When provided Cnh string has less digits than a Cnh.
+    ShortCnhString(FormatMismatch),
+    /// When provided numbers for digits (cnh digits or validation digits)
+    /// are out of bounds, in other words, they are not respecting the range of `0..=9`.
+    DigitsOutOfBounds,
+    /// When all digits of provided Cnh are the same, for example `11111111111`.
+    ///
+    /// Use [`Cnh::new_lenient`] to accept them.
+    RepeatedDigits,
+}
+
+impl LocalizedError for CnhCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            CnhCreationError::InvalidCnhDigits(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid CNH",
+                "CNH inválida",
+                mismatch,
+            ),
+            CnhCreationError::InvalidCnhStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid CNH format, expected ###########",
+                "formato de CNH inválido, esperado ###########",
+                mismatch,
+            ),
+            CnhCreationError::ShortCnhString(mismatch) => write_with_details(
+                f,
+                language,
+                "CNH has less than 11 digits",
+                "CNH possui menos de 11 dígitos",
+                mismatch,
+            ),
+            CnhCreationError::DigitsOutOfBounds => write_message(
+                f,
+                language,
+                "CNH digits must be in the range of 0 to 9",
+                "os dígitos da CNH devem estar entre 0 e 9",
+            ),
+            CnhCreationError::RepeatedDigits => write_message(
+                f,
+                language,
+                "invalid CNH: all digits are the same",
+                "CNH inválida: todos os dígitos são iguais",
+            ),
+        }
+    }
+}
+
+impl_error!(CnhCreationError);
+
+type VerifierDigits = (u8, u8);
+
+/// Algorithms used by DENATRAN to calculate the verifier digits of a Cnh.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum CnhAlgorithm {
+    /// Current algorithm, see [`calculate_verifier_digits`].
+    Current,
+    /// Legacy algorithm with the `dsc` adjustment, see [`calculate_legacy_verifier_digits`].
+    Legacy,
+}
+
+impl Cnh {
+    /// Creates a new Cnh if the provided `[digits]` and `[verifier_digits]` are valid according
+    /// to any of the [`CnhAlgorithm`]s.
+    ///
+    /// Cnhs with all digits repeated, such as `00000000000`, are rejected with
+    /// [`CnhCreationError::RepeatedDigits`], see [`Cnh::new_lenient`] for a constructor
+    /// which accepts them.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Cnh;
+    /// use validbr::cnh::CnhCreationError;
+    /// use validbr::error::VerifierDigitsMismatch;
+    ///
+    /// let cnh = Cnh::new([0, 2, 9, 7, 8, 5, 1, 5, 0], [7, 0]); // Valid CNH
+    /// assert!(cnh.is_ok());
+    ///
+    /// let cnh = Cnh::new([0, 2, 9, 7, 8, 5, 1, 5, 0], [7, 1]); // Invalid CNH
+    /// assert_eq!(cnh, Err(CnhCreationError::InvalidCnhDigits(VerifierDigitsMismatch { expected: [7, 0], found: [7, 1] })));
+    /// ```
+    pub fn new(digits: [u8; 9], verifier_digits: [u8; 2]) -> Result<Cnh, CnhCreationError> {
+        Cnh::create(digits, verifier_digits, false)
+    }
+
+    /// Creates a new Cnh if the provided `[digits]` and `[verifier_digits]` are valid, accepting
+    /// Cnhs with all digits repeated, such as `00000000000`.
+    ///
+    /// This constructor is meant for importing legacy data which must keep these Cnhs, prefer
+    /// [`Cnh::new`] otherwise.
+    pub fn new_lenient(digits: [u8; 9], verifier_digits: [u8; 2]) -> Result<Cnh, CnhCreationError> {
+        Cnh::create(digits, verifier_digits, true)
+    }
+
+    /// Checks whether all digits of this Cnh are the same, which is only possible for Cnhs
+    /// created through [`Cnh::new_lenient`].
+    pub fn has_repeated_digits(&self) -> bool {
+        has_repeated_digits(&self.digits, &self.verifier_digits)
+    }
+
+    /// Returns the algorithm which produces the verifier digits of this Cnh, the
+    /// [current](CnhAlgorithm::Current) algorithm is preferred when both produce them.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Cnh;
+    /// use validbr::cnh::CnhAlgorithm;
+    ///
+    /// assert_eq!(Cnh::parse_str("02978515070").unwrap().algorithm(), CnhAlgorithm::Current);
+    /// assert_eq!(Cnh::parse_str("89488293600").unwrap().algorithm(), CnhAlgorithm::Legacy);
+    /// ```
+    pub fn algorithm(&self) -> CnhAlgorithm {
+        let (first, second) = calculate_verifier_digits(self.digits);
+        if self.verifier_digits == [first, second] {
+            CnhAlgorithm::Current
+        } else {
+            CnhAlgorithm::Legacy
+        }
+    }
+
+    fn create(
+        digits: [u8; 9],
+        verifier_digits: [u8; 2],
+        allow_repeated_digits: bool,
+    ) -> Result<Cnh, CnhCreationError> {
+        let digits_is_valid = digits.iter().all(|i| *i <= 9);
+        let verifier_digits_is_valid = verifier_digits.iter().all(|i| *i <= 9);
+
+        if !digits_is_valid || !verifier_digits_is_valid {
+            return Err(CnhCreationError::DigitsOutOfBounds)
+        }
+
+        if !allow_repeated_digits && has_repeated_digits(&digits, &verifier_digits) {
+            return Err(CnhCreationError::RepeatedDigits)
+        }
+
+        let (first_verifier_digit, second_verifier_digit) = calculate_verifier_digits(digits);
+        let legacy = calculate_legacy_verifier_digits(digits);
+
+        if [first_verifier_digit, second_verifier_digit] == verifier_digits
+            || legacy == Some((verifier_digits[0], verifier_digits[1])) {
+            Ok(Cnh {
+                digits,
+                verifier_digits,
+            })
+        } else {
+            Err(CnhCreationError::InvalidCnhDigits(VerifierDigitsMismatch {
+                expected: [first_verifier_digit, second_verifier_digit],
+                found: verifier_digits,
+            }))
+        }
+    }
+
+    /// Parses a Cnh String to a [`Cnh`].
+    ///
+    /// Cnh registry numbers are only represented by their 11 digits, such as `02978515070`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cnh;
+    /// let cnh = Cnh::parse_str("02978515070");
+    /// assert_eq!(cnh, Ok(Cnh { digits: [0, 2, 9, 7, 8, 5, 1, 5, 0], verifier_digits: [7, 0]}));
+    /// ```
+    pub fn parse_str(cnh: &str) -> Result<Cnh, CnhCreationError> {
+        Cnh::parse_str_with(cnh, &ParseOptions::strict())
+    }
+
+    /// Parses a Cnh String to a [`Cnh`] according to the provided parse `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cnh;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let cnh = Cnh::parse_str_with("2978515070", &ParseOptions::padded());
+    /// assert_eq!(cnh, Ok(Cnh { digits: [0, 2, 9, 7, 8, 5, 1, 5, 0], verifier_digits: [7, 0]}));
+    /// ```
+    pub fn parse_str_with(cnh: &str, options: &ParseOptions) -> Result<Cnh, CnhCreationError> {
+        let cnh_only_with_numbers: [u8; 11] = parse::normalize(cnh, options, CNH_MASK)
+            .map_err(|e| match e {
+                FormatError::InvalidFormat(mismatch) => CnhCreationError::InvalidCnhStringFormat(mismatch),
+                FormatError::Short(mismatch) => CnhCreationError::ShortCnhString(mismatch),
+            })?;
+
+        let digits: [u8; 9] = core::array::from_fn(|i| cnh_only_with_numbers[i] - b'0');
+        let validators: [u8; 2] = core::array::from_fn(|i| cnh_only_with_numbers[9 + i] - b'0');
+
+        Cnh::create(digits, validators, options.allow_repeated_digits)
+    }
+}
+
+/// Parses a Cnh String to a [`Cnh`], see [`Cnh::parse_str`] for supported formats.
+impl FromStr for Cnh {
+    type Err = CnhCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cnh::parse_str(s)
+    }
+}
+
+/// Parses a Cnh String to a [`Cnh`], see [`Cnh::parse_str`] for supported formats.
+impl TryFrom<&str> for Cnh {
+    type Error = CnhCreationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Cnh::parse_str(value)
+    }
+}
+
+/// Checks whether all `digits` and `verifier_digits` are the same digit.
+fn has_repeated_digits(digits: &[u8; 9], verifier_digits: &[u8; 2]) -> bool {
+    digits.iter().chain(verifier_digits.iter()).all(|d| *d == digits[0])
+}
+
+/// Converts the remainder of a weighted sum to a verifier digit.
+const fn sum_to_verifier_digit(sum: u16) -> u8 {
+    let rest = (sum % 11) as u8;
+    if rest < 2 {
+        0
+    } else {
+        11 - rest
+    }
+}
+
+/// Calculates both verifier digits of the `digits` input with the current DENATRAN algorithm.
+///
+/// The first verifier digit is calculated over the 9 digits with weights `2..=10`, and the second
+/// one over the 9 digits and the first verifier digit with weights `3..=11` and `2`. A remainder
+/// `r` of the division of the sums by 11 produces the digit `11 - r`, or `0` if `r < 2`.
+///
+/// # Example
+///
+/// ```
+/// use validbr::cnh::calculate_verifier_digits;
+///
+/// assert_eq!(calculate_verifier_digits([0, 2, 9, 7, 8, 5, 1, 5, 0]), (7, 0));
+/// assert_eq!(calculate_verifier_digits([8, 9, 4, 8, 8, 2, 9, 3, 6]), (0, 8));
+/// ```
+pub const fn calculate_verifier_digits(digits: [u8; 9]) -> VerifierDigits {
+    let mut first_sum: u16 = 0;
+    let mut second_sum: u16 = 0;
+    let mut pos = 0;
+    while pos < 9 {
+        first_sum += (digits[pos] as u16) * (pos as u16 + 2);
+        second_sum += (digits[pos] as u16) * (pos as u16 + 3);
+        pos += 1;
+    }
+
+    let first_digit = sum_to_verifier_digit(first_sum);
+    let second_digit = sum_to_verifier_digit(second_sum + (first_digit as u16) * 2);
+
+    (first_digit, second_digit)
+}
+
+/// Calculates both verifier digits of the `digits` input with the legacy DENATRAN algorithm.
+///
+/// The first verifier digit is the remainder of the division by 11 of the sum of the 9 digits
+/// with weights `9..=1`. When it is `10` or greater, the digit is `0` and the `dsc` adjustment is `2`,
+/// otherwise `dsc` is `0`. The second verifier digit is the remainder of the division by 11 of the
+/// sum of the 9 digits with weights `1..=9` subtracted by `dsc`, or `0` if the remainder is `10`.
+///
+/// Returns [`None`] when the `dsc` adjustment produces a negative second verifier digit, in other
+/// words, no Cnh with these digits is valid according to the legacy algorithm.
+///
+/// Both algorithms produce the same verifier digits for most Cnhs, they only differ when the
+/// `dsc` adjustment is applied.
+///
+/// # Example
+///
+/// ```
+/// use validbr::cnh::calculate_legacy_verifier_digits;
+///
+/// assert_eq!(calculate_legacy_verifier_digits([0, 2, 9, 7, 8, 5, 1, 5, 0]), Some((7, 0)));
+/// assert_eq!(calculate_legacy_verifier_digits([8, 9, 4, 8, 8, 2, 9, 3, 6]), Some((0, 0)));
+/// ```
+pub const fn calculate_legacy_verifier_digits(digits: [u8; 9]) -> Option<VerifierDigits> {
+    let mut first_sum: u16 = 0;
+    let mut second_sum: u16 = 0;
+    let mut pos = 0;
+    while pos < 9 {
+        first_sum += (digits[pos] as u16) * (9 - pos as u16);
+        second_sum += (digits[pos] as u16) * (pos as u16 + 1);
+        pos += 1;
+    }
+
+    let (first_digit, dsc) = match (first_sum % 11) as u8 {
+        rest if rest >= 10 => (0, 2),
+        rest => (rest, 0),
+    };
+
+    match (second_sum % 11) as u8 {
+        rest if rest >= 10 => Some((first_digit, 0)),
+        rest if rest < dsc => None,
+        rest => Some((first_digit, rest - dsc)),
+    }
+}
+
+/// ## Random CNH Example
+///
+/// ```
+/// use validbr::Cnh;
+/// use validbr::cnh::*;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+/// let cnh: Cnh = rng.gen();
+///
+/// let verifier = validbr::cnh::calculate_verifier_digits(cnh.digits);
+///
+/// assert_eq!(verifier.0, cnh.verifier_digits[0]);
+/// assert_eq!(verifier.1, cnh.verifier_digits[1]);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Cnh> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cnh {
+        let uniform_int = Uniform::from(0u8..=9u8);
+        loop {
+            let digits_array: [u8; 9] = core::array::from_fn(|_| rng.sample(uniform_int));
+
+            let (first, second) = calculate_verifier_digits(digits_array);
+
+            // Repeated digits Cnhs are known to be invalid, so they must be generated again.
+            if !has_repeated_digits(&digits_array, &[first, second]) {
+                return Cnh::new(digits_array, [first, second])
+                    .expect("Generated Cnh MUST be valid at this point")
+            }
+        }
+    }
+}
+
+/// Driving license categories, including the combinations of category `A` (motorcycles) with
+/// the other categories.
+///
+/// Categories `B` to `E` are cumulative, for example, category `D` also allows driving vehicles
+/// of categories `B` and `C`, thus combinations such as `BC` are not valid.
+///
+/// # Example
+///
+/// ```
+/// use validbr::cnh::CnhCategory;
+///
+/// let category: CnhCategory = "AD".parse().unwrap();
+/// assert_eq!(category, CnhCategory::AD);
+/// assert!(category.includes(CnhCategory::A));
+/// assert!(category.includes(CnhCategory::C));
+/// assert!(!category.includes(CnhCategory::E));
+/// assert!("BC".parse::<CnhCategory>().is_err());
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum CnhCategory {
+    A,
+    B,
+    C,
+    D,
+    E,
+    AB,
+    AC,
+    AD,
+    AE,
+}
+
+impl CnhCategory {
+    /// Whether the category `A` (motorcycles) is included in this category.
+    pub fn has_motorcycle(&self) -> bool {
+        matches!(self, CnhCategory::A | CnhCategory::AB | CnhCategory::AC | CnhCategory::AD | CnhCategory::AE)
+    }
+
+    /// Highest level of the cumulative categories `B` to `E` included in this category, from `1`
+    /// (`B`) to `4` (`E`), or `0` if only category `A` is included.
+    fn level(&self) -> u8 {
+        match self {
+            CnhCategory::A => 0,
+            CnhCategory::B | CnhCategory::AB => 1,
+            CnhCategory::C | CnhCategory::AC => 2,
+            CnhCategory::D | CnhCategory::AD => 3,
+            CnhCategory::E | CnhCategory::AE => 4,
+        }
+    }
+
+    /// Checks whether this category allows driving the vehicles of `other` category.
+    pub fn includes(&self, other: CnhCategory) -> bool {
+        (self.has_motorcycle() || !other.has_motorcycle()) && self.level() >= other.level()
+    }
+}
+
+impl fmt::Display for CnhCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Parses a [`CnhCategory`], ignoring case.
+impl FromStr for CnhCategory {
+    type Err = InvalidCnhCategory;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let category = match s.as_bytes() {
+            [category] => match category.to_ascii_uppercase() {
+                b'A' => CnhCategory::A,
+                b'B' => CnhCategory::B,
+                b'C' => CnhCategory::C,
+                b'D' => CnhCategory::D,
+                b'E' => CnhCategory::E,
+                _ => return Err(InvalidCnhCategory),
+            },
+            [b'A' | b'a', category] => match category.to_ascii_uppercase() {
+                b'B' => CnhCategory::AB,
+                b'C' => CnhCategory::AC,
+                b'D' => CnhCategory::AD,
+                b'E' => CnhCategory::AE,
+                _ => return Err(InvalidCnhCategory),
+            },
+            _ => return Err(InvalidCnhCategory),
+        };
+
+        Ok(category)
+    }
+}
+
+/// Error returned when a string is not a valid [`CnhCategory`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct InvalidCnhCategory;
+
+impl LocalizedError for InvalidCnhCategory {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        write_message(
+            f,
+            language,
+            "invalid CNH category, expected A, B, C, D, E, AB, AC, AD or AE",
+            "categoria de CNH inválida, esperado A, B, C, D, E, AB, AC, AD ou AE",
+        )
+    }
+}
+
+impl_error!(InvalidCnhCategory);
+
+/// Calendar date of a driver's license, such as the first license and expiry dates.
+///
+/// # Example
+///
+/// ```
+/// use validbr::cnh::Date;
+///
+/// let date = Date::parse_str("29/02/2024").unwrap();
+/// assert_eq!(date, Date::new(2024, 2, 29).unwrap());
+/// assert_eq!(Date::parse_str("2024-02-29"), Ok(date));
+/// assert_eq!(format!("{}", date), "29/02/2024");
+/// assert!(Date::new(2023, 2, 29).is_err());
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a new Date if the `day` exists in the `month` of the `year`.
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Date, InvalidDate> {
+        let leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap_year => 29,
+            2 => 28,
+            _ => return Err(InvalidDate),
+        };
+
+        if day == 0 || day > days_in_month {
+            return Err(InvalidDate)
+        }
+
+        Ok(Date { year, month, day })
+    }
+
+    /// Parses a Date in the `DD/MM/YYYY` format, as printed in the driver's license, or in the
+    /// `YYYY-MM-DD` format.
+    pub fn parse_str(date: &str) -> Result<Date, InvalidDate> {
+        fn number<T: FromStr>(s: &str, len: usize) -> Result<T, InvalidDate> {
+            if s.len() != len || !s.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(InvalidDate)
+            }
+            s.parse().map_err(|_| InvalidDate)
+        }
+
+        let mut parts = date.split(['/', '-']);
+        let (first, second, third) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(first), Some(second), Some(third), None) => (first, second, third),
+            _ => return Err(InvalidDate),
+        };
+
+        if date.contains('/') && !date.contains('-') {
+            Date::new(number(third, 4)?, number(second, 2)?, number(first, 2)?)
+        } else if date.contains('-') && !date.contains('/') {
+            Date::new(number(first, 4)?, number(second, 2)?, number(third, 2)?)
+        } else {
+            Err(InvalidDate)
+        }
+    }
+
+    /// Year of this date.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Month of this date, in the range of `1..=12`.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month of this date, starting at `1`.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Date {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.day).cmp(&(other.year, other.month, other.day))
+    }
+}
+
+/// Formats Date in the `DD/MM/YYYY` format.
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}/{:02}/{:04}", self.day, self.month, self.year)
+    }
+}
+
+/// Parses a Date, see [`Date::parse_str`] for supported formats.
+impl FromStr for Date {
+    type Err = InvalidDate;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Date::parse_str(s)
+    }
+}
+
+/// Error returned when a [`Date`] does not exist or is not in a supported format.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct InvalidDate;
+
+impl LocalizedError for InvalidDate {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        write_message(
+            f,
+            language,
+            "invalid date, expected an existing date in the DD/MM/YYYY or YYYY-MM-DD format",
+            "data inválida, esperada uma data existente no formato DD/MM/AAAA ou AAAA-MM-DD",
+        )
+    }
+}
+
+impl_error!(InvalidDate);
+
+/// Information of a driver's license besides its registry number.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cnh;
+/// use validbr::cnh::{CnhCategory, CnhDetails, CnhDetailsError, Date};
+///
+/// let cnh = Cnh::parse_str("02978515070").unwrap();
+/// let first_license = Date::new(2010, 5, 20).ok();
+/// let expiry = Date::new(2030, 5, 20).ok();
+///
+/// let details = CnhDetails::new(cnh.clone(), CnhCategory::AB, first_license, expiry);
+/// assert!(details.is_ok());
+///
+/// let details = CnhDetails::new(cnh, CnhCategory::AB, expiry, first_license);
+/// assert_eq!(details, Err(CnhDetailsError::ExpiryBeforeFirstLicense));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCnhDetails"))]
+pub struct CnhDetails {
+    /// Registry number of the driver's license.
+    pub cnh: Cnh,
+    /// Category of the driver's license.
+    pub category: CnhCategory,
+    /// Date of the first driver's license.
+    pub first_license: Option<Date>,
+    /// Expiry date of the driver's license.
+    pub expiry: Option<Date>,
+}
+
+impl CnhDetails {
+    /// Creates a new CnhDetails if the provided dates are consistent, in other words, the
+    /// `expiry` date must be after the `first_license` date.
+    pub fn new(
+        cnh: Cnh,
+        category: CnhCategory,
+        first_license: Option<Date>,
+        expiry: Option<Date>,
+    ) -> Result<CnhDetails, CnhDetailsError> {
+        if let (Some(first_license), Some(expiry)) = (first_license, expiry) {
+            if expiry <= first_license {
+                return Err(CnhDetailsError::ExpiryBeforeFirstLicense)
+            }
+        }
+
+        Ok(CnhDetails {
+            cnh,
+            category,
+            first_license,
+            expiry,
+        })
+    }
+}
+
+/// Unvalidated [`CnhDetails`], which are validated through [`CnhDetails::new`] when deserialized.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawCnhDetails {
+    cnh: Cnh,
+    category: CnhCategory,
+    first_license: Option<Date>,
+    expiry: Option<Date>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCnhDetails> for CnhDetails {
+    type Error = CnhDetailsError;
+
+    fn try_from(raw: RawCnhDetails) -> Result<Self, Self::Error> {
+        CnhDetails::new(raw.cnh, raw.category, raw.first_license, raw.expiry)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum CnhDetailsError {
+    /// When the expiry date is not after the first license date.
+    ExpiryBeforeFirstLicense,
+}
+
+impl LocalizedError for CnhDetailsError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            CnhDetailsError::ExpiryBeforeFirstLicense => write_message(
+                f,
+                language,
+                "CNH expiry date must be after the first license date",
+                "a data de validade da CNH deve ser posterior à data da primeira habilitação",
+            ),
+        }
+    }
+}
+
+impl_error!(CnhDetailsError);
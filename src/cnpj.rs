@@ -29,26 +29,82 @@
 //! a CNPJ was successfully constructed with [`Cnpj::new`] or [`Cnpj::parse_str`] it means that the CNPJ
 //! is valid.
 //!
-//!
-//!
-use crate::append::ArrayAppend;
-use crate::cnpj::CnpjCreationError::CouldNotConvertCnpjToDigits;
-use crate::{ONLY_NUMBERS, Cnpj};
-use crate::NOT_NUMBERS;
-use crate::convert_to_u8;
-use crate::join_to_string;
-use regex::Regex;
-use std::convert::TryInto;
-use std::fmt;
-use std::fmt::Formatter;
+//! Besides the legacy numeric CNPJ, this module also supports the alphanumeric CNPJ format, where
+//! the eight root and four branch positions accept uppercase letters (`A` to `Z`) as well. Each
+//! character is represented by its ASCII code minus 48, so `0..=9` keeps the same value and
+//! `A..=Z` becomes `17..=42`, the verifier digits are always numeric.
+use crate::Cnpj;
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError, VerifierDigitsMismatch};
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use core::str::FromStr;
+use core::fmt::Formatter;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[cfg(feature = "rand")]
 use {
     rand::distributions::{Distribution, Standard, Uniform},
     rand::Rng,
 };
 
-lazy_static! {
-    static ref WELL_FORMATTED_CNPJ: Regex = Regex::new(r"\d{2}\.\d{3}\.\d{3}/\d{4}-\d{2}").unwrap();
+/// Mask of the well known Cnpj format, see [`parse::normalize`].
+const CNPJ_MASK: &str = "@@.@@@.@@@/@@@@-##";
+
+/// Converts a CNPJ character to the value used to represent and validate it, which is the
+/// ASCII code of the character minus 48.
+///
+/// Only `0..=9` and uppercase `A..=Z` are valid CNPJ characters.
+///
+/// # Example
+///
+/// ```
+/// use validbr::cnpj::char_to_value;
+///
+/// assert_eq!(char_to_value('7'), Some(7));
+/// assert_eq!(char_to_value('A'), Some(17));
+/// assert_eq!(char_to_value('Z'), Some(42));
+/// assert_eq!(char_to_value('a'), None);
+/// ```
+pub fn char_to_value(c: char) -> Option<u8> {
+    match c {
+        '0'..='9' | 'A'..='Z' => Some(c as u8 - b'0'),
+        _ => None,
+    }
+}
+
+/// Converts a CNPJ value back to its character, this is the inverse of [`char_to_value`].
+///
+/// # Example
+///
+/// ```
+/// use validbr::cnpj::value_to_char;
+///
+/// assert_eq!(value_to_char(7), Some('7'));
+/// assert_eq!(value_to_char(17), Some('A'));
+/// assert_eq!(value_to_char(10), None);
+/// ```
+pub fn value_to_char(value: u8) -> Option<char> {
+    if is_valid_value(value) {
+        Some((value + b'0') as char)
+    } else {
+        None
+    }
+}
+
+/// Checks whether `value` represents a valid CNPJ character, in other words, whether it is in
+/// the range of `0..=9` (digits) or `17..=42` (letters `A..=Z`).
+pub fn is_valid_value(value: u8) -> bool {
+    value <= 9 || (17..=42).contains(&value)
+}
+
+/// Writes CNPJ values as their respective characters.
+pub(crate) fn write_values(f: &mut Formatter<'_>, values: &[u8]) -> fmt::Result {
+    for v in values {
+        let c = value_to_char(*v).unwrap_or(char::from(v.wrapping_add(b'0')));
+        write!(f, "{}", c)?;
+    }
+    Ok(())
 }
 
 /// Formats Cnpj in the well known format:
@@ -61,16 +117,26 @@ lazy_static! {
 /// let cnpj = Cnpj::parse_str("80.906.404/0001-88").expect("Invalid cnpj.");
 /// assert_eq!(format!("{}", cnpj), "80.906.404/0001-88")
 /// ```
+///
+/// Alphanumeric CNPJs are formatted the same way:
+///
+/// ```
+/// use validbr::Cnpj;
+///
+/// let cnpj = Cnpj::parse_str("12ABC34501DE35").expect("Invalid cnpj.");
+/// assert_eq!(format!("{}", cnpj), "12.ABC.345/01DE-35")
+/// ```
 impl fmt::Display for Cnpj {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let f3 = join_to_string!(&self.digits[..2]);
-        let m3 = join_to_string!(&self.digits[2..5]);
-        let e3 = join_to_string!(&self.digits[5..8]);
-
-        let branch = join_to_string!(self.branch_digits);
-        let verifier = join_to_string!(self.verifier_digits);
-
-        write!(f, "{}.{}.{}/{}-{}", f3, m3, e3, branch, verifier)
+        write_values(f, &self.digits[..2])?;
+        f.write_str(".")?;
+        write_values(f, &self.digits[2..5])?;
+        f.write_str(".")?;
+        write_values(f, &self.digits[5..8])?;
+        f.write_str("/")?;
+        write_values(f, &self.branch_digits)?;
+        f.write_str("-")?;
+        write_values(f, &self.verifier_digits)
     }
 }
 
@@ -78,21 +144,87 @@ impl fmt::Display for Cnpj {
 pub enum CnpjCreationError {
     /// When provided Cnpj digits could not be validated against their verifier digits, in other
     /// words, when provided Cnpj is not valid.
-    InvalidCnpjDigits,
+    ///
+    /// Holds the expected and the provided verifier digits.
+    InvalidCnpjDigits(VerifierDigitsMismatch<2>),
     /// When provided Cnpj string is not a valid Cnpj format.
     ///
-    /// Supported Cpf formats are:
+    /// Supported Cnpj formats are:
     /// - 00.000.000/0000-00
     /// - 00000000000000
-    InvalidCnpjStringFormat,
+    /// - AA.AAA.AAA/AAAA-00 (alphanumeric)
+    /// - AAAAAAAAAAAA00 (alphanumeric)
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidCnpjStringFormat(FormatMismatch),
     /// When type conversion failure occurs.
     CouldNotConvertCnpjToDigits,
-    /// When provided Cnpj string is too short.
-    ShortCnpjString,
+    /// When provided Cnpj string has less characters than a Cnpj.
+    ShortCnpjString(FormatMismatch),
     /// When provided numbers for digits (cnpj digits, branch digits or validation digits)
-    /// are out of bounds, in other words, they are not respecting the range of `0..=9`.
-    /// All numbers in the digits array must respect the range `0..=9`.
+    /// are out of bounds.
+    /// All numbers in the cnpj and branch digits arrays must respect the range `0..=9` or `17..=42`
+    /// (letters `A..=Z`, see [`char_to_value`]), and all numbers in the verifier digits array must
+    /// respect the range `0..=9`.
     DigitsOutOfBounds,
+    /// When provided number has more than 14 digits, thus could not be converted to a Cnpj.
+    NumberOutOfRange,
+    /// When all digits of provided Cnpj are the same, for example `00.000.000/0000-00`.
+    ///
+    /// These Cnpjs are known to be invalid, use [`Cnpj::new_lenient`] to accept them.
+    RepeatedDigits,
+}
+
+impl LocalizedError for CnpjCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            CnpjCreationError::InvalidCnpjDigits(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid CNPJ",
+                "CNPJ inválido",
+                mismatch,
+            ),
+            CnpjCreationError::InvalidCnpjStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid CNPJ format, expected AA.AAA.AAA/AAAA-## or 14 characters, where A is a digit or an uppercase letter",
+                "formato de CNPJ inválido, esperado AA.AAA.AAA/AAAA-## ou 14 caracteres, onde A é um dígito ou uma letra maiúscula",
+                mismatch,
+            ),
+            CnpjCreationError::CouldNotConvertCnpjToDigits => write_message(
+                f,
+                language,
+                "could not convert CNPJ to digits",
+                "não foi possível converter o CNPJ em dígitos",
+            ),
+            CnpjCreationError::ShortCnpjString(mismatch) => write_with_details(
+                f,
+                language,
+                "CNPJ has less than 14 characters",
+                "CNPJ possui menos de 14 caracteres",
+                mismatch,
+            ),
+            CnpjCreationError::DigitsOutOfBounds => write_message(
+                f,
+                language,
+                "CNPJ digits must be in the range of 0 to 9 or A to Z, and verifier digits in the range of 0 to 9",
+                "os dígitos do CNPJ devem estar entre 0 e 9 ou A e Z, e os dígitos verificadores entre 0 e 9",
+            ),
+            CnpjCreationError::NumberOutOfRange => write_message(
+                f,
+                language,
+                "number has more than 14 digits and could not be converted to a CNPJ",
+                "o número possui mais de 14 dígitos e não pode ser convertido em CNPJ",
+            ),
+            CnpjCreationError::RepeatedDigits => write_message(
+                f,
+                language,
+                "invalid CNPJ: all digits are the same",
+                "CNPJ inválido: todos os dígitos são iguais",
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -101,11 +233,30 @@ pub enum DigitCalculationError {
     WrongAmountOfDigits(usize),
 }
 
+impl LocalizedError for DigitCalculationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match (self, language) {
+            (DigitCalculationError::WrongAmountOfDigits(amount), Language::English) => {
+                write!(f, "wrong amount of digits for CNPJ verifier digit calculation: {}", amount)
+            }
+            (DigitCalculationError::WrongAmountOfDigits(amount), Language::BrazilianPortuguese) => {
+                write!(f, "quantidade de dígitos incorreta para o cálculo do dígito verificador do CNPJ: {}", amount)
+            }
+        }
+    }
+}
+
+impl_error!(CnpjCreationError, DigitCalculationError);
+
 type VerifierDigits = (u8, u8);
 
 impl Cnpj {
     /// Creates a new Cnpj if the provided `[digits]`, `[branch_digits]` and `[verifier_digits]` are valid.
     ///
+    /// Cnpjs with all digits repeated, such as `00.000.000/0000-00`, are rejected with
+    /// [`CnpjCreationError::RepeatedDigits`], see [`Cnpj::new_lenient`] for a constructor which
+    /// accepts them.
+    ///
     /// # Example
     /// ```
     /// use validbr::Cnpj;
@@ -118,28 +269,91 @@ impl Cnpj {
     /// use validbr::Cnpj;
     /// use validbr::cnpj::CnpjCreationError;
     ///
+    /// use validbr::error::VerifierDigitsMismatch;
+    ///
     /// let cnpj = Cnpj::new([8, 0, 9, 0, 6, 4, 0, 4], [0, 0, 0, 3], [8, 8]); // Invalid CPF
-    /// assert_eq!(cnpj, Err(CnpjCreationError::InvalidCnpjDigits));
+    /// assert_eq!(cnpj, Err(CnpjCreationError::InvalidCnpjDigits(VerifierDigitsMismatch { expected: [4, 0], found: [8, 8] })));
+    /// ```
+    ///
+    /// Alphanumeric CNPJ uses the [`char_to_value`] representation for letters:
+    ///
+    /// ```
+    /// use validbr::Cnpj;
+    ///
+    /// let cnpj = Cnpj::new([1, 2, 17, 18, 19, 3, 4, 5], [0, 1, 20, 21], [3, 5]); // 12.ABC.345/01DE-35
+    /// assert!(cnpj.is_ok());
+    /// ```
+    ///
+    /// ```
+    /// use validbr::Cnpj;
+    /// use validbr::cnpj::CnpjCreationError;
+    ///
+    /// let cnpj = Cnpj::new([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0], [0, 0]); // Known invalid CNPJ
+    /// assert_eq!(cnpj, Err(CnpjCreationError::RepeatedDigits));
     /// ```
     pub fn new(
         digits: [u8; 8],
         branch_digits: [u8; 4],
         verifier_digits: [u8; 2],
     ) -> Result<Cnpj, CnpjCreationError> {
-        let digits_is_valid = digits.iter().all(|i| *i <= 9);
-        let branch_digits_is_valid = branch_digits.iter().all(|i| *i <= 9);
+        Cnpj::create(digits, branch_digits, verifier_digits, false)
+    }
+
+    /// Creates a new Cnpj if the provided `[digits]`, `[branch_digits]` and `[verifier_digits]` are valid,
+    /// accepting Cnpjs with all digits repeated, such as `00.000.000/0000-00`.
+    ///
+    /// This constructor is meant for importing legacy data which must keep these Cnpjs, prefer
+    /// [`Cnpj::new`] otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Cnpj;
+    ///
+    /// let cnpj = Cnpj::new_lenient([0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0], [0, 0]);
+    /// assert!(cnpj.is_ok());
+    /// assert!(cnpj.unwrap().has_repeated_digits());
+    /// ```
+    pub fn new_lenient(
+        digits: [u8; 8],
+        branch_digits: [u8; 4],
+        verifier_digits: [u8; 2],
+    ) -> Result<Cnpj, CnpjCreationError> {
+        Cnpj::create(digits, branch_digits, verifier_digits, true)
+    }
+
+    /// Checks whether all digits of this Cnpj are the same, which is only possible for Cnpjs
+    /// created through [`Cnpj::new_lenient`].
+    pub fn has_repeated_digits(&self) -> bool {
+        has_repeated_digits(&self.digits, &self.branch_digits, &self.verifier_digits)
+    }
+
+    fn create(
+        digits: [u8; 8],
+        branch_digits: [u8; 4],
+        verifier_digits: [u8; 2],
+        allow_repeated_digits: bool,
+    ) -> Result<Cnpj, CnpjCreationError> {
+        let digits_is_valid = digits.iter().all(|i| is_valid_value(*i));
+        let branch_digits_is_valid = branch_digits.iter().all(|i| is_valid_value(*i));
         let verifier_digits_is_valid = verifier_digits.iter().all(|i| *i <= 9);
 
         if !digits_is_valid || !branch_digits_is_valid || !verifier_digits_is_valid {
             return Err(CnpjCreationError::DigitsOutOfBounds)
         }
 
+        if !allow_repeated_digits && has_repeated_digits(&digits, &branch_digits, &verifier_digits) {
+            return Err(CnpjCreationError::RepeatedDigits)
+        }
+
         let (first_verifier_digit, second_verifier_digit) =
             calculate_verifier_digits(digits, branch_digits);
 
         if first_verifier_digit != verifier_digits[0]
             || second_verifier_digit != verifier_digits[1] {
-            Err(CnpjCreationError::InvalidCnpjDigits)
+            Err(CnpjCreationError::InvalidCnpjDigits(VerifierDigitsMismatch {
+                expected: [first_verifier_digit, second_verifier_digit],
+                found: verifier_digits,
+            }))
         } else {
             Ok(Cnpj {
                 digits,
@@ -149,12 +363,32 @@ impl Cnpj {
         }
     }
 
+    /// Checks whether this Cnpj has letters in its root or branch, in other words, whether it is
+    /// in the alphanumeric CNPJ format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cnpj;
+    ///
+    /// assert!(Cnpj::parse_str("12.ABC.345/01DE-35").unwrap().is_alphanumeric());
+    /// assert!(!Cnpj::parse_str("53.871.143/0001-35").unwrap().is_alphanumeric());
+    /// ```
+    pub fn is_alphanumeric(&self) -> bool {
+        self.digits.iter().chain(self.branch_digits.iter()).any(|v| *v > 9)
+    }
+
     /// Parses a Cnpj String to a [`Cnpj`].
     ///
     /// Supported Cnpj formats are:
     ///
-    /// - 000.000.000-00
-    /// - 00000000000
+    /// - 00.000.000/0000-00
+    /// - 00000000000000
+    /// - AA.AAA.AAA/AAAA-00 (alphanumeric)
+    /// - AAAAAAAAAAAA00 (alphanumeric)
+    ///
+    /// The entire string must match one of these formats, see [`Cnpj::parse_str_with`] for
+    /// parsing Cnpjs with formatting noise or missing leading zeros.
     ///
     /// # Examples
     ///
@@ -171,49 +405,241 @@ impl Cnpj {
     /// assert!(cnpj.is_ok());
     /// assert_eq!(cnpj, Ok(Cnpj { digits: [5, 3, 8, 7, 1, 1, 4, 3], branch_digits: [0, 0, 0, 1], verifier_digits: [3, 5]}));
     /// ```
+    ///
+    /// ```
+    /// use validbr::Cnpj;
+    /// let cnpj = Cnpj::parse_str("12.ABC.345/01DE-35");
+    /// assert!(cnpj.is_ok());
+    /// assert_eq!(cnpj, Ok(Cnpj { digits: [1, 2, 17, 18, 19, 3, 4, 5], branch_digits: [0, 1, 20, 21], verifier_digits: [3, 5]}));
+    /// ```
     pub fn parse_str(cnpj: &str) -> Result<Cnpj, CnpjCreationError> {
-        let only_numbers = ONLY_NUMBERS.is_match(cnpj);
-        if only_numbers && cnpj.len() != 14 {
-            return Err(CnpjCreationError::ShortCnpjString);
-        }
+        Cnpj::parse_str_with(cnpj, &ParseOptions::strict())
+    }
+
+    /// Parses a Cnpj String to a [`Cnpj`] in a const context, panicking if the string is not a
+    /// valid Cnpj in one of the formats accepted by [`Cnpj::parse_str`].
+    ///
+    /// This function is meant to be used by the [`cnpj!`](crate::cnpj!) macro, which turns the
+    /// panic into a compilation error.
+    #[doc(hidden)]
+    pub const fn parse_const(cnpj: &str) -> Cnpj {
+        let chars: [u8; 14] = match parse::normalize_const(cnpj, CNPJ_MASK) {
+            Some(chars) => chars,
+            None => panic!("invalid CNPJ format, expected AA.AAA.AAA/AAAA-## or 14 characters, where A is a digit or an uppercase letter"),
+        };
 
-        return if (ONLY_NUMBERS.is_match(cnpj) && cnpj.len() == 14) || (WELL_FORMATTED_CNPJ.is_match(cnpj))
-        {
-            let cnpj_only_with_numbers = NOT_NUMBERS.replace_all(cnpj, "");
-
-            let digits_vec: Option<Vec<u8>> =
-                convert_to_u8!(cnpj_only_with_numbers.chars().take(8)).collect();
-            let branch_digits_vec: Option<Vec<u8>> =
-                convert_to_u8!(cnpj_only_with_numbers.chars().skip(8).take(4)).collect();
-            let validators_vec: Option<Vec<u8>> =
-                convert_to_u8!(cnpj_only_with_numbers.chars().skip(12)).collect();
-
-            let digits_array: Option<[u8; 8]> = digits_vec.and_then(|v| v.try_into().ok());
-            let branch_digits_array: Option<[u8; 4]> =
-                branch_digits_vec.and_then(|v| v.try_into().ok());
-            let validators_array: Option<[u8; 2]> = validators_vec.and_then(|v| v.try_into().ok());
-
-            if let Some(digits) = digits_array {
-                if let Some(validators) = validators_array {
-                    if let Some(branch_digits) = branch_digits_array {
-                        Cnpj::new(digits, branch_digits, validators)
-                    } else {
-                        Err(CouldNotConvertCnpjToDigits)
-                    }
-                } else {
-                    Err(CouldNotConvertCnpjToDigits)
-                }
+        // Normalized characters are always digits or uppercase letters, see `parse_str_with`.
+        let mut digits = [0u8; 8];
+        let mut branch_digits = [0u8; 4];
+        let mut pos = 0;
+        while pos < 12 {
+            if pos < 8 {
+                digits[pos] = chars[pos] - b'0';
             } else {
-                Err(CouldNotConvertCnpjToDigits)
+                branch_digits[pos - 8] = chars[pos] - b'0';
             }
-        } else {
-            Err(CnpjCreationError::InvalidCnpjStringFormat)
-        };
+            pos += 1;
+        }
+        let verifier_digits = [chars[12] - b'0', chars[13] - b'0'];
+
+        if has_repeated_digits(&digits, &branch_digits, &verifier_digits) {
+            panic!("invalid CNPJ: all digits are the same")
+        }
+
+        let (first_verifier_digit, second_verifier_digit) = calculate_verifier_digits(digits, branch_digits);
+        if first_verifier_digit != verifier_digits[0] || second_verifier_digit != verifier_digits[1] {
+            panic!("invalid CNPJ: verifier digits do not match")
+        }
+
+        Cnpj {
+            digits,
+            branch_digits,
+            verifier_digits,
+        }
+    }
+
+    /// Parses a Cnpj String to a [`Cnpj`] according to the provided parse `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validbr::Cnpj;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let cnpj = Cnpj::parse_str_with(" 12.abc.345/01de-35 ", &ParseOptions::lenient());
+    /// assert_eq!(cnpj, Ok(Cnpj { digits: [1, 2, 17, 18, 19, 3, 4, 5], branch_digits: [0, 1, 20, 21], verifier_digits: [3, 5]}));
+    /// ```
+    ///
+    /// ```
+    /// use validbr::Cnpj;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let cnpj = Cnpj::parse_str_with("360305000104", &ParseOptions::padded());
+    /// assert_eq!(cnpj, Ok(Cnpj { digits: [0, 0, 3, 6, 0, 3, 0, 5], branch_digits: [0, 0, 0, 1], verifier_digits: [0, 4]}));
+    /// ```
+    pub fn parse_str_with(cnpj: &str, options: &ParseOptions) -> Result<Cnpj, CnpjCreationError> {
+        let cnpj_only_alphanumeric: [u8; 14] = parse::normalize(cnpj, options, CNPJ_MASK)
+            .map_err(|e| match e {
+                FormatError::InvalidFormat(mismatch) => CnpjCreationError::InvalidCnpjStringFormat(mismatch),
+                FormatError::Short(mismatch) => CnpjCreationError::ShortCnpjString(mismatch),
+            })?;
+
+        // Normalized characters are always digits or uppercase letters, thus their values are
+        // obtained by subtracting the ASCII code of `0`, just like `char_to_value`.
+        let digits: [u8; 8] = core::array::from_fn(|i| cnpj_only_alphanumeric[i] - b'0');
+        let branch_digits: [u8; 4] = core::array::from_fn(|i| cnpj_only_alphanumeric[8 + i] - b'0');
+        let validators: [u8; 2] = core::array::from_fn(|i| cnpj_only_alphanumeric[12 + i] - b'0');
+
+        Cnpj::create(digits, branch_digits, validators, options.allow_repeated_digits)
+    }
+}
+
+/// Parses a Cnpj String to a [`Cnpj`], see [`Cnpj::parse_str`] for supported formats.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cnpj;
+///
+/// let cnpj = "12.345.678/0001-95".parse::<Cnpj>();
+/// assert_eq!(cnpj, Ok(Cnpj { digits: [1, 2, 3, 4, 5, 6, 7, 8], branch_digits: [0, 0, 0, 1], verifier_digits: [9, 5]}));
+/// ```
+impl FromStr for Cnpj {
+    type Err = CnpjCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cnpj::parse_str(s)
+    }
+}
+
+/// Parses a Cnpj String to a [`Cnpj`], see [`Cnpj::parse_str`] for supported formats.
+impl TryFrom<&str> for Cnpj {
+    type Error = CnpjCreationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Cnpj::parse_str(value)
+    }
+}
+
+/// Converts a number to a numeric [`Cnpj`], the number is padded with leading zeros, so numbers
+/// stored without the leading zeros of the Cnpj are supported.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cnpj;
+/// use validbr::cnpj::CnpjCreationError;
+/// use std::convert::TryFrom;
+///
+/// let cnpj = Cnpj::try_from(360305000104u64); // 00.360.305/0001-04
+/// assert_eq!(cnpj, Ok(Cnpj { digits: [0, 0, 3, 6, 0, 3, 0, 5], branch_digits: [0, 0, 0, 1], verifier_digits: [0, 4]}));
+/// assert_eq!(Cnpj::try_from(123456780001950u64), Err(CnpjCreationError::NumberOutOfRange));
+/// ```
+impl TryFrom<u64> for Cnpj {
+    type Error = CnpjCreationError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value > 99_999_999_999_999 {
+            return Err(CnpjCreationError::NumberOutOfRange)
+        }
+
+        let mut all_digits = [0u8; 14];
+        let mut remaining = value;
+        for digit in all_digits.iter_mut().rev() {
+            *digit = (remaining % 10) as u8;
+            remaining /= 10;
+        }
+
+        let digits: [u8; 8] = all_digits[..8].try_into()
+            .expect("Conversion of slice with 8 elements MUST be possible at this point.");
+        let branch_digits: [u8; 4] = all_digits[8..12].try_into()
+            .expect("Conversion of slice with 4 elements MUST be possible at this point.");
+        let verifier_digits: [u8; 2] = all_digits[12..].try_into()
+            .expect("Conversion of slice with 2 elements MUST be possible at this point.");
+
+        Cnpj::new(digits, branch_digits, verifier_digits)
+    }
+}
+
+/// When an alphanumeric [`Cnpj`] is converted to a number, which is only possible for numeric Cnpjs.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct AlphanumericCnpjToNumber;
+
+impl LocalizedError for AlphanumericCnpjToNumber {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        write_message(
+            f,
+            language,
+            "alphanumeric CNPJ could not be converted to a number",
+            "CNPJ alfanumérico não pode ser convertido em número",
+        )
+    }
+}
+
+impl_error!(AlphanumericCnpjToNumber);
+
+/// Converts a numeric [`Cnpj`] to its number representation, the leading zeros of the Cnpj are
+/// lost in this representation.
+///
+/// Alphanumeric Cnpjs could not be represented as numbers, thus fail with [`AlphanumericCnpjToNumber`].
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cnpj;
+/// use validbr::cnpj::AlphanumericCnpjToNumber;
+/// use std::convert::TryFrom;
+///
+/// let cnpj = Cnpj::parse_str("00.360.305/0001-04").expect("Invalid cnpj.");
+/// assert_eq!(u64::try_from(&cnpj), Ok(360305000104));
+///
+/// let cnpj = Cnpj::parse_str("12.ABC.345/01DE-35").expect("Invalid cnpj.");
+/// assert_eq!(u64::try_from(&cnpj), Err(AlphanumericCnpjToNumber));
+/// ```
+impl TryFrom<&Cnpj> for u64 {
+    type Error = AlphanumericCnpjToNumber;
+
+    fn try_from(cnpj: &Cnpj) -> Result<Self, Self::Error> {
+        if cnpj.is_alphanumeric() {
+            return Err(AlphanumericCnpjToNumber)
+        }
+
+        Ok(cnpj.digits
+            .iter()
+            .chain(cnpj.branch_digits.iter())
+            .chain(cnpj.verifier_digits.iter())
+            .fold(0u64, |acc, d| acc * 10 + (*d as u64)))
+    }
+}
+
+/// Converts a numeric [`Cnpj`] to its number representation, failing for alphanumeric Cnpjs.
+impl TryFrom<Cnpj> for u64 {
+    type Error = AlphanumericCnpjToNumber;
+
+    fn try_from(cnpj: Cnpj) -> Result<Self, Self::Error> {
+        u64::try_from(&cnpj)
+    }
+}
+
+/// Checks whether all `digits`, `branch_digits` and `verifier_digits` are the same digit.
+const fn has_repeated_digits(digits: &[u8; 8], branch_digits: &[u8; 4], verifier_digits: &[u8; 2]) -> bool {
+    let mut pos = 0;
+    while pos < 8 {
+        if digits[pos] != digits[0] || (pos < 4 && branch_digits[pos] != digits[0]) {
+            return false
+        }
+        pos += 1;
     }
+
+    verifier_digits[0] == digits[0] && verifier_digits[1] == digits[0]
 }
 
 /// Calculates the verifier digit given input `[cnpj_digits]`.
 ///
+/// Letters of alphanumeric CNPJ must be provided in their [`char_to_value`] representation.
+///
 /// This function does not care about the amount of digits provided to it, but the correct amount
 /// of digits to be provided to this function is either 12 CNPJ digits with branch digits or 13 values
 /// (12 CNPJ digits with branch digits and first verifier digit). When provided with 12 CPF digits, the function calculates
@@ -234,16 +660,18 @@ impl Cnpj {
 ///
 /// assert_eq!(calculate_verifier_digit([3, 1, 2, 3, 8, 8, 2, 6, 0, 0, 0, 1]), 1);
 /// assert_eq!(calculate_verifier_digit([3, 1, 2, 3, 8, 8, 2, 6, 0, 0, 0, 1, 1]), 7);
+///
+/// assert_eq!(calculate_verifier_digit([1, 2, 17, 18, 19, 3, 4, 5, 0, 1, 20, 21]), 3);
+/// assert_eq!(calculate_verifier_digit([1, 2, 17, 18, 19, 3, 4, 5, 0, 1, 20, 21, 3]), 5);
 /// ```
 ///
-pub fn calculate_verifier_digit<const S: usize>(cnpj_digits: [u8; S]) -> u8 {
-    let mul_digits: Vec<u8> = get_multiplier_values(S);
-
-    let digits_sum: u16 = cnpj_digits
-        .iter()
-        .enumerate()
-        .map(|(pos, digit)| (*digit as u16) * (mul_digits[pos] as u16))
-        .sum();
+pub const fn calculate_verifier_digit<const S: usize>(cnpj_digits: [u8; S]) -> u8 {
+    let mut digits_sum: u16 = 0;
+    let mut pos = 0;
+    while pos < S {
+        digits_sum += (cnpj_digits[pos] as u16) * (multiplier_value(S, pos) as u16);
+        pos += 1;
+    }
 
     let pre_verifier_digit = (digits_sum % 11) as u8;
 
@@ -269,15 +697,16 @@ pub fn calculate_verifier_digit<const S: usize>(cnpj_digits: [u8; S]) -> u8 {
 /// assert_eq!(get_multiplier_values(13), vec![6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]);
 /// ```
 ///
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub fn get_multiplier_values(amount: usize) -> Vec<u8> {
-    let range = 2u8..=9u8;
-    let range_rev_cycle = range.cycle();
-    range_rev_cycle
-        .take(amount)
-        .collect::<Vec<u8>>()
-        .into_iter()
-        .rev()
-        .collect()
+    (0..amount).map(|pos| multiplier_value(amount, pos)).collect()
+}
+
+/// Calculates the multiplier value at `position` for CNPJ verifier digit calculation given the
+/// `amount` of digits, see [`get_multiplier_values`].
+const fn multiplier_value(amount: usize, position: usize) -> u8 {
+    2 + ((amount - 1 - position) % 8) as u8
 }
 
 /// Calculate both first and second verifier digits, given the `[digits]` input.
@@ -291,12 +720,26 @@ pub fn get_multiplier_values(amount: usize) -> Vec<u8> {
 ///
 /// assert_eq!(calculate_verifier_digits([2, 7, 1, 4, 8, 7, 3, 4], [0, 0, 0, 1]), (7, 9));
 /// assert_eq!(calculate_verifier_digits([1, 2, 3, 4, 5, 6, 7, 8], [9, 0, 1, 2]), (3, 0));
+/// assert_eq!(calculate_verifier_digits([1, 2, 17, 18, 19, 3, 4, 5], [0, 1, 20, 21]), (3, 5));
+///
+/// const VERIFIER_DIGITS: (u8, u8) = calculate_verifier_digits([2, 7, 1, 4, 8, 7, 3, 4], [0, 0, 0, 1]);
+/// assert_eq!(VERIFIER_DIGITS, (7, 9));
 /// ```
-pub fn calculate_verifier_digits(digits: [u8; 8], branch_digits: [u8; 4]) -> VerifierDigits {
-    let cnpj_digits: [u8; 12] = digits.append_array::<4>(branch_digits);
+pub const fn calculate_verifier_digits(digits: [u8; 8], branch_digits: [u8; 4]) -> VerifierDigits {
+    let mut cnpj_digits = [0u8; 12];
+    let mut pos = 0;
+    while pos < 12 {
+        cnpj_digits[pos] = if pos < 8 { digits[pos] } else { branch_digits[pos - 8] };
+        pos += 1;
+    }
     let first_digit = calculate_verifier_digit::<12>(cnpj_digits);
 
-    let digits_with_first_verifier: [u8; 13] = cnpj_digits.append(first_digit);
+    let mut digits_with_first_verifier = [first_digit; 13];
+    let mut pos = 0;
+    while pos < 12 {
+        digits_with_first_verifier[pos] = cnpj_digits[pos];
+        pos += 1;
+    }
     let second_digit = calculate_verifier_digit::<13>(digits_with_first_verifier);
 
     (first_digit, second_digit)
@@ -316,47 +759,51 @@ pub fn calculate_verifier_digits(digits: [u8; 8], branch_digits: [u8; 4]) -> Ver
 /// assert_eq!(verifier.1, cnpj.verifier_digits[1]);
 /// ```
 #[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 impl Distribution<Cnpj> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cnpj {
         let uniform_int = Uniform::from(0u8..=9u8);
-        let digits: Vec<u8> = rng.sample_iter(uniform_int)
-            .take(8)
-            .collect();
-
-        let branch_digits: Vec<u8> = rng.sample_iter(uniform_int)
-            .take(4)
-            .collect();
-
-        let digits_array: [u8; 8] = digits.try_into()
-            .expect("Conversion of Vec with 8 elements MUST be possible at this point.");
+        let branch_digits_array: [u8; 4] = core::array::from_fn(|_| rng.sample(uniform_int));
 
-        let branch_digits_array: [u8; 4] = branch_digits.try_into()
-            .expect("Conversion of Vec with 4 elements MUST be possible at this point.");
-
-        let (first, second) = calculate_verifier_digits(digits_array, branch_digits_array);
-
-        Cnpj::new(digits_array, branch_digits_array, [first, second])
-            .expect("Generated Cnpj MUST be valid at this point")
+        Branch(branch_digits_array).sample(rng)
     }
 }
 
-/// Struct object used to generate random [`Cnpj`] based in provided [`Branch::0`] instead of
+/// Struct object used to generate random [`Cnpj`] based in provided branch digits instead of
 /// generating a random branch digit.
-#[cfg_attr(feature = "rand", derive(Debug, Eq, PartialEq, Hash, Clone))]
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
 pub struct Branch([u8; 4]);
 
 #[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 impl fmt::Display for Branch {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", join_to_string!(self.0))
+        write_values(f, &self.0)
     }
 }
 
-/// When branch number is not in the range of `0..=9999`.
-#[cfg_attr(feature = "rand", derive(Debug, Eq, PartialEq, Hash, Clone))]
+/// When branch number is not in the range of `0..=9999`, or when branch characters are not valid
+/// CNPJ characters.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
 pub struct InvalidBranchNumber;
 
+impl LocalizedError for InvalidBranchNumber {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        write_message(
+            f,
+            language,
+            "invalid CNPJ branch, expected four characters in the range of 0 to 9 or A to Z",
+            "filial do CNPJ inválida, esperado quatro caracteres entre 0 e 9 ou A e Z",
+        )
+    }
+}
+
+impl_error!(InvalidBranchNumber);
+
 #[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 impl Branch {
     /// Creates the [`Branch`] object representing the first
     /// company Cnpj number registered in the Brazilian Governmental Organizations responsible
@@ -369,11 +816,11 @@ impl Branch {
 
     /// Creates a branch from provided `branch_digits`.
     ///
-    /// Every number in the `branch_digits` array must be in the range of `0..=9`,
-    /// otherwise this method will fail.
+    /// Every number in the `branch_digits` array must be in the range of `0..=9` or `17..=42`
+    /// (alphanumeric branch, see [`char_to_value`]), otherwise this method will fail.
     pub fn new(branch_digits: [u8; 4]) -> Result<Branch, InvalidBranchNumber> {
         for b in &branch_digits {
-            if *b > 9 {
+            if !is_valid_value(*b) {
                 return Err(InvalidBranchNumber)
             }
         }
@@ -381,6 +828,31 @@ impl Branch {
         Ok(Branch(branch_digits))
     }
 
+    /// Creates a Branch object from provided `branch` string, which must have exactly
+    /// four characters in the range of `0..=9` or `A..=Z`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::cnpj::Branch;
+    ///
+    /// assert_eq!(Branch::parse_str("01DE"), Branch::new([0, 1, 20, 21]));
+    /// assert!(Branch::parse_str("01de").is_err());
+    /// ```
+    pub fn parse_str(branch: &str) -> Result<Branch, InvalidBranchNumber> {
+        let mut values = [0u8; 4];
+        let mut chars = branch.chars();
+        for value in values.iter_mut() {
+            *value = chars.next().and_then(char_to_value).ok_or(InvalidBranchNumber)?;
+        }
+
+        if chars.next().is_some() {
+            return Err(InvalidBranchNumber)
+        }
+
+        Branch::new(values)
+    }
+
     /// Creates a Branch object from provided `branch_number`.
     ///
     /// The `branch_number` must be in the range of `0..=9999`, otherwise this method will fail.
@@ -410,19 +882,78 @@ impl Branch {
 /// assert_eq!(cnpj.branch_digits, [0, 0, 0, 4])
 /// ```
 #[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 impl Distribution<Cnpj> for Branch {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cnpj {
         let uniform_int = Uniform::from(0u8..=9u8);
-        let digits: Vec<u8> = rng.sample_iter(uniform_int)
-            .take(8)
-            .collect();
+        loop {
+            let digits_array: [u8; 8] = core::array::from_fn(|_| rng.sample(uniform_int));
 
-        let digits_array: [u8; 8] = digits.try_into()
-            .expect("Conversion of Vec with 8 elements MUST be possible at this point.");
+            let (first, second) = calculate_verifier_digits(digits_array, self.0);
 
-        let (first, second) = calculate_verifier_digits(digits_array, self.0);
+            // Repeated digits Cnpjs are known to be invalid, so they must be generated again.
+            if !has_repeated_digits(&digits_array, &self.0, &[first, second]) {
+                return Cnpj::new(digits_array, self.0, [first, second])
+                    .expect("Generated Cnpj MUST be valid at this point")
+            }
+        }
+    }
+}
+
+/// Samples a random CNPJ character value, uniformly distributed over `0..=9` and `A..=Z`.
+#[cfg(feature = "rand")]
+fn sample_alphanumeric_value<R: Rng + ?Sized>(rng: &mut R) -> u8 {
+    let index = rng.sample(Uniform::from(0u8..36u8));
+    if index < 10 {
+        index
+    } else {
+        index + 7
+    }
+}
+
+/// Struct object used to generate random alphanumeric [`Cnpj`], in which root and branch characters
+/// are randomly picked from `0..=9` and `A..=Z`.
+///
+/// ## Random alphanumeric CNPJ example
+///
+/// ```
+/// use validbr::Cnpj;
+/// use validbr::cnpj::*;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+/// let cnpj: Cnpj = rng.sample(AlphanumericCnpj);
+///
+/// let verifier = validbr::cnpj::calculate_verifier_digits(cnpj.digits, cnpj.branch_digits);
+/// assert_eq!(verifier.0, cnpj.verifier_digits[0]);
+/// assert_eq!(verifier.1, cnpj.verifier_digits[1]);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct AlphanumericCnpj;
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Cnpj> for AlphanumericCnpj {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cnpj {
+        loop {
+            let mut digits_array = [0u8; 8];
+            for digit in digits_array.iter_mut() {
+                *digit = sample_alphanumeric_value(rng);
+            }
+
+            let mut branch_digits_array = [0u8; 4];
+            for digit in branch_digits_array.iter_mut() {
+                *digit = sample_alphanumeric_value(rng);
+            }
+
+            let (first, second) = calculate_verifier_digits(digits_array, branch_digits_array);
 
-        Cnpj::new(digits_array, self.0, [first, second])
-            .expect("Generated Cnpj MUST be valid at this point")
+            // Repeated digits Cnpjs are known to be invalid, so they must be generated again.
+            if !has_repeated_digits(&digits_array, &branch_digits_array, &[first, second]) {
+                return Cnpj::new(digits_array, branch_digits_array, [first, second])
+                    .expect("Generated Cnpj MUST be valid at this point")
+            }
+        }
     }
 }
@@ -0,0 +1,426 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # CNS
+//!
+//! This module provides utility for constructing and validating CNS (Cartão Nacional de Saúde)
+//! numbers. If a CNS was successfully constructed with [`Cns::new`] or [`Cns::parse_str`] it
+//! means that the CNS is valid.
+//!
+//! The CNS is validated with one of two algorithms according to its
+//! [`CnsKind`](crate::cns::CnsKind), which is identified by the first digit:
+//!
+//! - `1` or `2`: [`CnsKind::Definitive`](crate::cns::CnsKind::Definitive), the first 11 digits are
+//!   the PIS of the citizen, followed by `000` or `001` and a verifier digit, see
+//!   [`calculate_definitive_digits`](crate::cns::calculate_definitive_digits).
+//! - `7`, `8` or `9`: [`CnsKind::Provisional`](crate::cns::CnsKind::Provisional), the sum of the
+//!   digits multiplied by the weights `15` to `1` must be a multiple of 11.
+use crate::{Cns, Pis};
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError, VerifierDigitsMismatch};
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Formatter;
+use core::str::FromStr;
+#[cfg(feature = "rand")]
+use {
+    rand::distributions::{Distribution, Standard, Uniform},
+    rand::Rng,
+};
+
+/// Mask of the CNS format, see [`parse::normalize`].
+const CNS_MASK: &str = "### #### #### ####";
+
+/// Formats the CNS as `### #### #### ####`, which is how it is printed in the card.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cns;
+/// let cns = Cns::parse_str("120123456720009").expect("Invalid cns.");
+/// assert_eq!(format!("{}", cns), "120 1234 5672 0009")
+/// ```
+impl fmt::Display for Cns {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, digit) in self.digits.iter().enumerate() {
+            if i == 3 || i == 7 || i == 11 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum CnsCreationError {
+    /// When the last 4 digits of provided definitive CNS does not match the digits calculated
+    /// from its PIS, in other words, when provided CNS is not valid.
+    ///
+    /// Holds the expected and the provided last 4 digits.
+    InvalidCnsDigits(VerifierDigitsMismatch<4>),
+    /// When the weighted sum of the digits of provided provisional CNS is not a multiple of 11,
+    /// in other words, when provided CNS is not valid.
+    InvalidProvisionalCns,
+    /// When provided CNS string is not a valid CNS format.
+    ///
+    /// Supported CNS formats are:
+    /// - 000 0000 0000 0000
+    /// - 000000000000000
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidCnsStringFormat(FormatMismatch),
+    /// When provided CNS string has less digits than a CNS.
+    ShortCnsString(FormatMismatch),
+    /// When provided numbers for digits are out of bounds, in other words, they are not respecting
+    /// the range of `0..=9`.
+    DigitsOutOfBounds,
+    /// When the first digit of provided CNS does not identify a [`CnsKind`].
+    ///
+    /// Holds the first digit.
+    InvalidCnsKind(u8),
+}
+
+impl LocalizedError for CnsCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            CnsCreationError::InvalidCnsDigits(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid CNS",
+                "CNS inválido",
+                mismatch,
+            ),
+            CnsCreationError::InvalidProvisionalCns => write_message(
+                f,
+                language,
+                "invalid provisional CNS: weighted sum of the digits is not a multiple of 11",
+                "CNS provisório inválido: a soma ponderada dos dígitos não é múltipla de 11",
+            ),
+            CnsCreationError::InvalidCnsStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid CNS format, expected ### #### #### #### or ###############",
+                "formato de CNS inválido, esperado ### #### #### #### ou ###############",
+                mismatch,
+            ),
+            CnsCreationError::ShortCnsString(mismatch) => write_with_details(
+                f,
+                language,
+                "CNS has less than 15 digits",
+                "CNS possui menos de 15 dígitos",
+                mismatch,
+            ),
+            CnsCreationError::DigitsOutOfBounds => write_message(
+                f,
+                language,
+                "CNS digits must be in the range of 0 to 9",
+                "os dígitos do CNS devem estar entre 0 e 9",
+            ),
+            CnsCreationError::InvalidCnsKind(digit) => match language {
+                Language::English => write!(f, "invalid CNS: first digit must be 1, 2, 7, 8 or 9, found {}", digit),
+                Language::BrazilianPortuguese => {
+                    write!(f, "CNS inválido: o primeiro dígito deve ser 1, 2, 7, 8 ou 9, encontrado {}", digit)
+                }
+            },
+        }
+    }
+}
+
+impl_error!(CnsCreationError);
+
+/// Kind of a CNS, identified by its first digit.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum CnsKind {
+    /// Definitive CNS, starting with `1` or `2`, which is derived from the PIS of the citizen.
+    Definitive,
+    /// Provisional CNS, starting with `7`, `8` or `9`, issued while the citizen has no definitive CNS.
+    Provisional,
+}
+
+impl CnsKind {
+    /// Resolves the CnsKind identified by the `first_digit` of a CNS.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::cns::CnsKind;
+    ///
+    /// assert_eq!(CnsKind::from_first_digit(2), Some(CnsKind::Definitive));
+    /// assert_eq!(CnsKind::from_first_digit(8), Some(CnsKind::Provisional));
+    /// assert_eq!(CnsKind::from_first_digit(3), None);
+    /// ```
+    pub fn from_first_digit(first_digit: u8) -> Option<CnsKind> {
+        match first_digit {
+            1 | 2 => Some(CnsKind::Definitive),
+            7..=9 => Some(CnsKind::Provisional),
+            _ => None,
+        }
+    }
+}
+
+impl Cns {
+    /// Creates a new Cns if the provided `[digits]` are valid according to the algorithm of its
+    /// [`CnsKind`].
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Cns;
+    /// use validbr::cns::CnsCreationError;
+    ///
+    /// let cns = Cns::new([1, 2, 0, 1, 2, 3, 4, 5, 6, 7, 2, 0, 0, 0, 9]); // Valid definitive CNS
+    /// assert!(cns.is_ok());
+    ///
+    /// let cns = Cns::new([7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4]); // Invalid provisional CNS
+    /// assert_eq!(cns, Err(CnsCreationError::InvalidProvisionalCns));
+    /// ```
+    pub fn new(digits: [u8; 15]) -> Result<Cns, CnsCreationError> {
+        if !digits.iter().all(|d| *d <= 9) {
+            return Err(CnsCreationError::DigitsOutOfBounds)
+        }
+
+        match CnsKind::from_first_digit(digits[0]) {
+            Some(CnsKind::Definitive) => {
+                let pis: [u8; 11] = core::array::from_fn(|i| digits[i]);
+                let found: [u8; 4] = core::array::from_fn(|i| digits[11 + i]);
+                let expected = calculate_definitive_digits(pis);
+                if expected == found {
+                    Ok(Cns { digits })
+                } else {
+                    Err(CnsCreationError::InvalidCnsDigits(VerifierDigitsMismatch { expected, found }))
+                }
+            }
+            Some(CnsKind::Provisional) => {
+                if weighted_sum(&digits) % 11 == 0 {
+                    Ok(Cns { digits })
+                } else {
+                    Err(CnsCreationError::InvalidProvisionalCns)
+                }
+            }
+            None => Err(CnsCreationError::InvalidCnsKind(digits[0])),
+        }
+    }
+
+    /// Creates the definitive Cns derived from the provided `pis`.
+    ///
+    /// Only Pis starting with `1` or `2` have a definitive Cns.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::{Cns, Pis};
+    /// use validbr::cns::CnsCreationError;
+    ///
+    /// let pis = Pis::parse_str("120.12345.67-2").unwrap();
+    /// assert_eq!(Cns::from_pis(&pis), Cns::parse_str("120 1234 5672 0009"));
+    ///
+    /// let pis = Pis::parse_str("012.34567.89-7").unwrap();
+    /// assert_eq!(Cns::from_pis(&pis), Err(CnsCreationError::InvalidCnsKind(0)));
+    /// ```
+    pub fn from_pis(pis: &Pis) -> Result<Cns, CnsCreationError> {
+        let pis_digits: [u8; 11] = core::array::from_fn(|i| if i < 10 { pis.digits[i] } else { pis.verifier_digit });
+        if CnsKind::from_first_digit(pis_digits[0]) != Some(CnsKind::Definitive) {
+            return Err(CnsCreationError::InvalidCnsKind(pis_digits[0]))
+        }
+
+        let definitive_digits = calculate_definitive_digits(pis_digits);
+        Cns::new(core::array::from_fn(|i| if i < 11 { pis_digits[i] } else { definitive_digits[i - 11] }))
+    }
+
+    /// Parses a CNS String to a [`Cns`].
+    ///
+    /// Supported formats are:
+    /// - `### #### #### ####` (Printed in the card)
+    /// - `###############` (Only digits)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cns;
+    /// let cns = Cns::parse_str("898 0010 0000 9310");
+    /// assert_eq!(cns, Ok(Cns { digits: [8, 9, 8, 0, 0, 1, 0, 0, 0, 0, 0, 9, 3, 1, 0] }));
+    /// assert_eq!(Cns::parse_str("898001000009310"), cns);
+    /// ```
+    pub fn parse_str(cns: &str) -> Result<Cns, CnsCreationError> {
+        Cns::parse_str_with(cns, &ParseOptions::strict())
+    }
+
+    /// Parses a CNS String to a [`Cns`] according to the provided parse `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cns;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let cns = Cns::parse_str_with("898.0010.0000.9310", &ParseOptions::lenient());
+    /// assert_eq!(cns, Cns::parse_str("898 0010 0000 9310"));
+    /// ```
+    pub fn parse_str_with(cns: &str, options: &ParseOptions) -> Result<Cns, CnsCreationError> {
+        let normalized: [u8; 15] = parse::normalize(cns, options, CNS_MASK)
+            .map_err(|e| match e {
+                FormatError::InvalidFormat(mismatch) => CnsCreationError::InvalidCnsStringFormat(mismatch),
+                FormatError::Short(mismatch) => CnsCreationError::ShortCnsString(mismatch),
+            })?;
+
+        Cns::new(core::array::from_fn(|i| normalized[i] - b'0'))
+    }
+
+    /// Kind of this CNS, see [`CnsKind`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cns;
+    /// use validbr::cns::CnsKind;
+    ///
+    /// assert_eq!(Cns::parse_str("120 1234 5672 0009").unwrap().kind(), CnsKind::Definitive);
+    /// assert_eq!(Cns::parse_str("898 0010 0000 9310").unwrap().kind(), CnsKind::Provisional);
+    /// ```
+    pub fn kind(&self) -> CnsKind {
+        CnsKind::from_first_digit(self.digits[0]).expect("Cns MUST have a valid kind at this point")
+    }
+}
+
+/// Parses a CNS String to a [`Cns`], see [`Cns::parse_str`] for supported formats.
+impl FromStr for Cns {
+    type Err = CnsCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cns::parse_str(s)
+    }
+}
+
+/// Parses a CNS String to a [`Cns`], see [`Cns::parse_str`] for supported formats.
+impl TryFrom<&str> for Cns {
+    type Error = CnsCreationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Cns::parse_str(value)
+    }
+}
+
+/// Sum of the `digits` multiplied by the weights `15` to `1`.
+const fn weighted_sum(digits: &[u8]) -> u16 {
+    let mut sum: u16 = 0;
+    let mut pos = 0;
+    while pos < digits.len() {
+        sum += (digits[pos] as u16) * (15 - pos as u16);
+        pos += 1;
+    }
+    sum
+}
+
+/// Calculates the last 4 digits of a definitive CNS from the 11 digits of its `pis`.
+///
+/// The digits of the PIS are multiplied by the weights `15` to `5`, and a remainder `r` of the
+/// division of the sum by 11 produces the verifier digit `11 - r`, or `0` if `11 - r` is `11`.
+/// The verifier digit is preceded by `000`, unless it is `10`, in which case the sum is
+/// incremented by `2` (the weight of the `1` in `001`) and the digits are `001` followed by the
+/// recalculated verifier digit.
+///
+/// # Example
+///
+/// ```
+/// use validbr::cns::calculate_definitive_digits;
+///
+/// assert_eq!(calculate_definitive_digits([1, 2, 0, 1, 2, 3, 4, 5, 6, 7, 2]), [0, 0, 0, 9]);
+/// assert_eq!(calculate_definitive_digits([1, 2, 0, 1, 2, 3, 4, 5, 6, 7, 4]), [0, 0, 1, 8]);
+/// ```
+pub const fn calculate_definitive_digits(pis: [u8; 11]) -> [u8; 4] {
+    let sum = weighted_sum(&pis);
+    match 11 - (sum % 11) as u8 {
+        11 => [0, 0, 0, 0],
+        10 => [0, 0, 1, 11 - ((sum + 2) % 11) as u8],
+        digit => [0, 0, 0, digit],
+    }
+}
+
+/// ## Random CNS Example
+///
+/// ```
+/// use validbr::Cns;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+/// let cns: Cns = rng.gen();
+///
+/// assert_eq!(Cns::new(cns.digits), Ok(cns));
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Cns> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cns {
+        if rng.gen() {
+            CnsKind::Definitive.sample(rng)
+        } else {
+            CnsKind::Provisional.sample(rng)
+        }
+    }
+}
+
+/// ## Random CNS of a CnsKind Example
+///
+/// ```
+/// use validbr::Cns;
+/// use validbr::cns::CnsKind;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+///
+/// let cns: Cns = rng.sample(CnsKind::Provisional);
+/// assert_eq!(cns.kind(), CnsKind::Provisional);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Cns> for CnsKind {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cns {
+        let uniform_int = Uniform::from(0u8..=9u8);
+        match self {
+            CnsKind::Definitive => {
+                let first_digit = rng.sample(Uniform::from(1u8..=2u8));
+                let mut pis: [u8; 11] = core::array::from_fn(|_| rng.sample(uniform_int));
+                pis[0] = first_digit;
+                let definitive_digits = calculate_definitive_digits(pis);
+
+                Cns::new(core::array::from_fn(|i| if i < 11 { pis[i] } else { definitive_digits[i - 11] }))
+                    .expect("Generated Cns MUST be valid at this point")
+            }
+            CnsKind::Provisional => loop {
+                let first_digit = rng.sample(Uniform::from(7u8..=9u8));
+                let mut digits: [u8; 15] = core::array::from_fn(|_| rng.sample(uniform_int));
+                digits[0] = first_digit;
+                digits[14] = 0;
+
+                // The last digit has weight 1, so it must complete the sum to a multiple of 11,
+                // which is not possible when 10 is required.
+                let last_digit = (11 - (weighted_sum(&digits) % 11) as u8) % 11;
+                if last_digit < 10 {
+                    digits[14] = last_digit;
+                    return Cns::new(digits).expect("Generated Cns MUST be valid at this point")
+                }
+            },
+        }
+    }
+}
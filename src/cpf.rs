@@ -28,25 +28,21 @@
 //! This module provides utility for constructing and manipulating CPF, as well as validating CPFs. If
 //! a CPF was successfully constructed with [`Cpf::new`] or [`Cpf::parse_str`] it means that the CPF
 //! is valid.
-use crate::append::ArrayAppend;
-use crate::{ONLY_NUMBERS, Cpf};
-use crate::NOT_NUMBERS;
-use crate::convert_to_u8;
-use crate::join_to_string;
-use crate::cpf::CpfCreationError::CouldNotConvertCpfToDigits;
-use regex::Regex;
-use std::convert::TryInto;
-use std::fmt;
-use std::fmt::Formatter;
+use crate::{Cpf, UF};
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError, VerifierDigitsMismatch};
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use core::str::FromStr;
+use core::fmt::Formatter;
 #[cfg(feature = "rand")]
 use {
     rand::distributions::{Distribution, Standard,  Uniform},
     rand::Rng,
 };
 
-lazy_static! {
-    static ref WELL_FORMATTED_CPF: Regex = Regex::new(r"\d{3}\.\d{3}\.\d{3}-\d{2}").unwrap();
-}
+/// Mask of the well known Cpf format, see [`parse::normalize`].
+const CPF_MASK: &str = "###.###.###-##";
 
 /// Formats Cpf in the well known format:
 /// 000.000.000-00
@@ -59,13 +55,14 @@ lazy_static! {
 /// ```
 impl fmt::Display for Cpf {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let f3 = join_to_string!(&self.digits[..3]);
-        let m3 = join_to_string!(&self.digits[3..6]);
-        let e3 = join_to_string!(&self.digits[6..9]);
-
-        let verifier = join_to_string!(self.verifier_digits);
+        let d = &self.digits;
+        let v = &self.verifier_digits;
 
-        write!(f, "{}.{}.{}-{}", f3, m3, e3, verifier)
+        write!(
+            f,
+            "{}{}{}.{}{}{}.{}{}{}-{}{}",
+            d[0], d[1], d[2], d[3], d[4], d[5], d[6], d[7], d[8], v[0], v[1]
+        )
     }
 }
 
@@ -73,21 +70,84 @@ impl fmt::Display for Cpf {
 pub enum CpfCreationError {
     /// When provided Cpf digits could not be validated against their verifier digits, in other
     /// words, when provided Cpf is not valid.
-    InvalidCpfDigits,
+    ///
+    /// Holds the expected and the provided verifier digits.
+    InvalidCpfDigits(VerifierDigitsMismatch<2>),
     /// When provided Cpf string is not a valid Cpf format.
     ///
     /// Supported Cpf formats are:
     /// - 000.000.000-00
     /// - 00000000000
-    InvalidCpfStringFormat,
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidCpfStringFormat(FormatMismatch),
     /// When type conversion failure occurs.
     CouldNotConvertCpfToDigits,
-    /// When provided Cpf string is too short.
-    ShortCpfString,
+    /// When provided Cpf string has less digits than a Cpf.
+    ShortCpfString(FormatMismatch),
     /// When provided numbers for digits (cpf digits or validation digits)
     /// are out of bounds, in other words, they are not respecting the range of `0..=9`.
     /// All numbers in the digits array must respect the range `0..=9`.
     DigitsOutOfBounds,
+    /// When provided number has more than 11 digits, thus could not be converted to a Cpf.
+    NumberOutOfRange,
+    /// When all digits of provided Cpf are the same, for example `111.111.111-11`.
+    ///
+    /// These Cpfs pass the verifier digits check, but are known to be invalid. Use
+    /// [`Cpf::new_lenient`] to accept them.
+    RepeatedDigits,
+}
+
+impl LocalizedError for CpfCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            CpfCreationError::InvalidCpfDigits(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid CPF",
+                "CPF inválido",
+                mismatch,
+            ),
+            CpfCreationError::InvalidCpfStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid CPF format, expected ###.###.###-## or ###########",
+                "formato de CPF inválido, esperado ###.###.###-## ou ###########",
+                mismatch,
+            ),
+            CpfCreationError::CouldNotConvertCpfToDigits => write_message(
+                f,
+                language,
+                "could not convert CPF to digits",
+                "não foi possível converter o CPF em dígitos",
+            ),
+            CpfCreationError::ShortCpfString(mismatch) => write_with_details(
+                f,
+                language,
+                "CPF has less than 11 digits",
+                "CPF possui menos de 11 dígitos",
+                mismatch,
+            ),
+            CpfCreationError::DigitsOutOfBounds => write_message(
+                f,
+                language,
+                "CPF digits must be in the range of 0 to 9",
+                "os dígitos do CPF devem estar entre 0 e 9",
+            ),
+            CpfCreationError::NumberOutOfRange => write_message(
+                f,
+                language,
+                "number has more than 11 digits and could not be converted to a CPF",
+                "o número possui mais de 11 dígitos e não pode ser convertido em CPF",
+            ),
+            CpfCreationError::RepeatedDigits => write_message(
+                f,
+                language,
+                "invalid CPF: all digits are the same",
+                "CPF inválido: todos os dígitos são iguais",
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -96,11 +156,30 @@ pub enum DigitCalculationError {
     WrongAmountOfDigits(usize),
 }
 
+impl LocalizedError for DigitCalculationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match (self, language) {
+            (DigitCalculationError::WrongAmountOfDigits(amount), Language::English) => {
+                write!(f, "wrong amount of digits for CPF verifier digit calculation: {}", amount)
+            }
+            (DigitCalculationError::WrongAmountOfDigits(amount), Language::BrazilianPortuguese) => {
+                write!(f, "quantidade de dígitos incorreta para o cálculo do dígito verificador do CPF: {}", amount)
+            }
+        }
+    }
+}
+
+impl_error!(CpfCreationError, DigitCalculationError);
+
 type VerifierDigits = (u8, u8);
 
 impl Cpf {
     /// Creates a new Cpf if the provided `[digits]` and `[verifier_digits]` are valid.
     ///
+    /// Cpfs with all digits repeated, such as `000.000.000-00` and `111.111.111-11`, are rejected
+    /// with [`CpfCreationError::RepeatedDigits`], see [`Cpf::new_lenient`] for a constructor
+    /// which accepts them.
+    ///
     /// # Example
     /// ```
     /// use validbr::Cpf;
@@ -113,10 +192,67 @@ impl Cpf {
     /// use validbr::Cpf;
     /// use validbr::cpf::CpfCreationError;
     ///
+    /// use validbr::error::VerifierDigitsMismatch;
+    ///
     /// let cpf = Cpf::new([1, 2, 3, 4, 5, 6, 7, 8, 9], [1, 0]); // Invalid CPF
-    /// assert_eq!(cpf, Err(CpfCreationError::InvalidCpfDigits));
+    /// assert_eq!(cpf, Err(CpfCreationError::InvalidCpfDigits(VerifierDigitsMismatch { expected: [0, 9], found: [1, 0] })));
+    /// ```
+    ///
+    /// ```
+    /// use validbr::Cpf;
+    /// use validbr::cpf::CpfCreationError;
+    ///
+    /// let cpf = Cpf::new([1, 1, 1, 1, 1, 1, 1, 1, 1], [1, 1]); // Known invalid CPF
+    /// assert_eq!(cpf, Err(CpfCreationError::RepeatedDigits));
     /// ```
     pub fn new(digits: [u8; 9], verifier_digits: [u8; 2]) -> Result<Cpf, CpfCreationError> {
+        Cpf::create(digits, verifier_digits, false)
+    }
+
+    /// Creates a new Cpf if the provided `[digits]` and `[verifier_digits]` are valid, accepting
+    /// Cpfs with all digits repeated, such as `000.000.000-00`.
+    ///
+    /// This constructor is meant for importing legacy data which must keep these Cpfs, prefer
+    /// [`Cpf::new`] otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Cpf;
+    ///
+    /// let cpf = Cpf::new_lenient([0, 0, 0, 0, 0, 0, 0, 0, 0], [0, 0]);
+    /// assert!(cpf.is_ok());
+    /// assert!(cpf.unwrap().has_repeated_digits());
+    /// ```
+    pub fn new_lenient(digits: [u8; 9], verifier_digits: [u8; 2]) -> Result<Cpf, CpfCreationError> {
+        Cpf::create(digits, verifier_digits, true)
+    }
+
+    /// Returns the [`FiscalRegion`] which issued this Cpf, encoded in the ninth digit.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Cpf;
+    /// use validbr::UF;
+    ///
+    /// let cpf = Cpf::parse_str("123.456.788-10").unwrap();
+    /// assert_eq!(cpf.fiscal_region().number(), 8);
+    /// assert_eq!(cpf.fiscal_region().ufs(), &[UF::SP]);
+    /// ```
+    pub const fn fiscal_region(&self) -> FiscalRegion {
+        FiscalRegion { digit: self.digits[8] }
+    }
+
+    /// Checks whether all digits of this Cpf are the same, which is only possible for Cpfs
+    /// created through [`Cpf::new_lenient`].
+    pub fn has_repeated_digits(&self) -> bool {
+        has_repeated_digits(&self.digits, &self.verifier_digits)
+    }
+
+    fn create(
+        digits: [u8; 9],
+        verifier_digits: [u8; 2],
+        allow_repeated_digits: bool,
+    ) -> Result<Cpf, CpfCreationError> {
         let digits_is_valid = digits.iter().all(|i| *i <= 9);
         let verifier_digits_is_valid = verifier_digits.iter().all(|i| *i <= 9);
 
@@ -124,11 +260,18 @@ impl Cpf {
             return Err(CpfCreationError::DigitsOutOfBounds)
         }
 
+        if !allow_repeated_digits && has_repeated_digits(&digits, &verifier_digits) {
+            return Err(CpfCreationError::RepeatedDigits)
+        }
+
         let (first_verifier_digit, second_verifier_digit) = calculate_verifier_digits(digits);
 
         if first_verifier_digit != verifier_digits[0]
             || second_verifier_digit != verifier_digits[1] {
-            Err(CpfCreationError::InvalidCpfDigits)
+            Err(CpfCreationError::InvalidCpfDigits(VerifierDigitsMismatch {
+                expected: [first_verifier_digit, second_verifier_digit],
+                found: verifier_digits,
+            }))
         } else {
             Ok(Cpf {
                 digits,
@@ -144,6 +287,9 @@ impl Cpf {
     /// - 000.000.000-00
     /// - 00000000000
     ///
+    /// The entire string must match one of these formats, see [`Cpf::parse_str_with`] for
+    /// parsing Cpfs with formatting noise or missing leading zeros.
+    ///
     /// # Examples
     ///
     /// ```
@@ -167,35 +313,288 @@ impl Cpf {
     /// assert_eq!(cpf, Ok(Cpf { digits: [1, 2, 3, 4, 5, 6, 7, 8, 9], verifier_digits: [0, 9]}));
     /// ```
     pub fn parse_str(cpf: &str) -> Result<Cpf, CpfCreationError> {
-        let only_numbers = ONLY_NUMBERS.is_match(cpf);
-        if only_numbers && cpf.len() != 11 {
-            return Err(CpfCreationError::ShortCpfString);
+        Cpf::parse_str_with(cpf, &ParseOptions::strict())
+    }
+
+    /// Parses a Cpf String to a [`Cpf`] in a const context, panicking if the string is not a
+    /// valid Cpf in one of the formats accepted by [`Cpf::parse_str`].
+    ///
+    /// This function is meant to be used by the [`cpf!`](crate::cpf!) macro, which turns the
+    /// panic into a compilation error.
+    #[doc(hidden)]
+    pub const fn parse_const(cpf: &str) -> Cpf {
+        let chars: [u8; 11] = match parse::normalize_const(cpf, CPF_MASK) {
+            Some(chars) => chars,
+            None => panic!("invalid CPF format, expected ###.###.###-## or 11 digits"),
+        };
+
+        let mut digits = [0u8; 9];
+        let mut pos = 0;
+        while pos < 9 {
+            digits[pos] = chars[pos] - b'0';
+            pos += 1;
         }
+        let verifier_digits = [chars[9] - b'0', chars[10] - b'0'];
 
-        if (ONLY_NUMBERS.is_match(cpf) && cpf.len() == 11) || (WELL_FORMATTED_CPF.is_match(cpf)) {
-            let cpf_only_with_numbers = NOT_NUMBERS.replace_all(cpf, "");
-
-            let digits_vec: Option<Vec<u8>> =
-                convert_to_u8!(cpf_only_with_numbers.chars().take(9)).collect();
-            let validators_vec: Option<Vec<u8>> =
-                convert_to_u8!(cpf_only_with_numbers.chars().skip(9)).collect();
-
-            let digits_array: Option<[u8; 9]> = digits_vec.and_then(|v| v.try_into().ok());
-            let validators_array: Option<[u8; 2]> = validators_vec.and_then(|v| v.try_into().ok());
-
-            if let Some(digits) = digits_array {
-                if let Some(validators) = validators_array {
-                    Cpf::new(digits, validators)
-                } else {
-                    Err(CouldNotConvertCpfToDigits)
-                }
-            } else {
-                Err(CouldNotConvertCpfToDigits)
-            }
+        if has_repeated_digits(&digits, &verifier_digits) {
+            panic!("invalid CPF: all digits are the same")
+        }
+
+        let (first_verifier_digit, second_verifier_digit) = calculate_verifier_digits(digits);
+        if first_verifier_digit != verifier_digits[0] || second_verifier_digit != verifier_digits[1] {
+            panic!("invalid CPF: verifier digits do not match")
+        }
+
+        Cpf {
+            digits,
+            verifier_digits,
+        }
+    }
+
+    /// Parses a Cpf String to a [`Cpf`] according to the provided parse `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validbr::Cpf;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let cpf = Cpf::parse_str_with(" 123 456 789 09 ", &ParseOptions::lenient());
+    /// assert_eq!(cpf, Ok(Cpf { digits: [1, 2, 3, 4, 5, 6, 7, 8, 9], verifier_digits: [0, 9]}));
+    /// ```
+    ///
+    /// ```
+    /// use validbr::Cpf;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let cpf = Cpf::parse_str_with("191", &ParseOptions::padded());
+    /// assert_eq!(cpf, Ok(Cpf { digits: [0, 0, 0, 0, 0, 0, 0, 0, 1], verifier_digits: [9, 1]}));
+    /// ```
+    pub fn parse_str_with(cpf: &str, options: &ParseOptions) -> Result<Cpf, CpfCreationError> {
+        let cpf_only_with_numbers: [u8; 11] = parse::normalize(cpf, options, CPF_MASK)
+            .map_err(|e| match e {
+                FormatError::InvalidFormat(mismatch) => CpfCreationError::InvalidCpfStringFormat(mismatch),
+                FormatError::Short(mismatch) => CpfCreationError::ShortCpfString(mismatch),
+            })?;
+
+        let digits: [u8; 9] = core::array::from_fn(|i| cpf_only_with_numbers[i] - b'0');
+        let validators: [u8; 2] = core::array::from_fn(|i| cpf_only_with_numbers[9 + i] - b'0');
+
+        Cpf::create(digits, validators, options.allow_repeated_digits)
+    }
+}
+
+/// Parses a Cpf String to a [`Cpf`], see [`Cpf::parse_str`] for supported formats.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cpf;
+///
+/// let cpf = "123.456.789-09".parse::<Cpf>();
+/// assert_eq!(cpf, Ok(Cpf { digits: [1, 2, 3, 4, 5, 6, 7, 8, 9], verifier_digits: [0, 9]}));
+/// ```
+impl FromStr for Cpf {
+    type Err = CpfCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cpf::parse_str(s)
+    }
+}
+
+/// Parses a Cpf String to a [`Cpf`], see [`Cpf::parse_str`] for supported formats.
+impl TryFrom<&str> for Cpf {
+    type Error = CpfCreationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Cpf::parse_str(value)
+    }
+}
+
+/// Converts a number to a [`Cpf`], the number is padded with leading zeros, so numbers stored
+/// without the leading zeros of the Cpf are supported.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cpf;
+/// use validbr::cpf::CpfCreationError;
+/// use std::convert::TryFrom;
+///
+/// let cpf = Cpf::try_from(191u64); // 000.000.001-91
+/// assert_eq!(cpf, Ok(Cpf { digits: [0, 0, 0, 0, 0, 0, 0, 0, 1], verifier_digits: [9, 1]}));
+/// assert_eq!(Cpf::try_from(123456789090u64), Err(CpfCreationError::NumberOutOfRange));
+/// ```
+impl TryFrom<u64> for Cpf {
+    type Error = CpfCreationError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value > 99_999_999_999 {
+            return Err(CpfCreationError::NumberOutOfRange)
+        }
+
+        let mut all_digits = [0u8; 11];
+        let mut remaining = value;
+        for digit in all_digits.iter_mut().rev() {
+            *digit = (remaining % 10) as u8;
+            remaining /= 10;
+        }
+
+        let digits: [u8; 9] = all_digits[..9].try_into()
+            .expect("Conversion of slice with 9 elements MUST be possible at this point.");
+        let verifier_digits: [u8; 2] = all_digits[9..].try_into()
+            .expect("Conversion of slice with 2 elements MUST be possible at this point.");
+
+        Cpf::new(digits, verifier_digits)
+    }
+}
+
+/// Converts a [`Cpf`] to its number representation, the leading zeros of the Cpf are lost in
+/// this representation.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cpf;
+///
+/// let cpf = Cpf::parse_str("000.000.001-91").expect("Invalid cpf.");
+/// assert_eq!(u64::from(cpf), 191);
+/// ```
+impl From<&Cpf> for u64 {
+    fn from(cpf: &Cpf) -> Self {
+        cpf.digits
+            .iter()
+            .chain(cpf.verifier_digits.iter())
+            .fold(0u64, |acc, d| acc * 10 + (*d as u64))
+    }
+}
+
+/// Converts a [`Cpf`] to its number representation, the leading zeros of the Cpf are lost in
+/// this representation.
+impl From<Cpf> for u64 {
+    fn from(cpf: Cpf) -> Self {
+        u64::from(&cpf)
+    }
+}
+
+/// Fiscal region of the Receita Federal which issued a Cpf, encoded in the ninth digit
+/// of the Cpf.
+///
+/// | Digit | Region | UFs                    |
+/// |-------|--------|------------------------|
+/// | 1     | 1ª     | DF, GO, MS, MT, TO     |
+/// | 2     | 2ª     | AC, AM, AP, PA, RO, RR |
+/// | 3     | 3ª     | CE, MA, PI             |
+/// | 4     | 4ª     | AL, PB, PE, RN         |
+/// | 5     | 5ª     | BA, SE                 |
+/// | 6     | 6ª     | MG                     |
+/// | 7     | 7ª     | ES, RJ                 |
+/// | 8     | 8ª     | SP                     |
+/// | 9     | 9ª     | PR, SC                 |
+/// | 0     | 10ª    | RS                     |
+///
+/// # Example
+///
+/// ```
+/// use validbr::cpf::FiscalRegion;
+/// use validbr::UF;
+///
+/// let region = FiscalRegion::of(&UF::RS);
+/// assert_eq!(region.digit(), 0);
+/// assert_eq!(region.number(), 10);
+/// assert_eq!(format!("{}", region), "10ª Região Fiscal");
+/// assert!(FiscalRegion::from_digit(1).unwrap().contains(&UF::GO));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct FiscalRegion {
+    digit: u8,
+}
+
+impl FiscalRegion {
+    /// Creates the fiscal region encoded by the ninth Cpf `digit`, which must be in the range
+    /// of `0..=9`.
+    pub const fn from_digit(digit: u8) -> Option<FiscalRegion> {
+        if digit <= 9 {
+            Some(FiscalRegion { digit })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the fiscal region responsible for the provided `uf`.
+    pub fn of(uf: &UF) -> FiscalRegion {
+        let digit = match uf {
+            UF::RS => 0,
+            UF::DF | UF::GO | UF::MS | UF::MT | UF::TO => 1,
+            UF::AC | UF::AM | UF::AP | UF::PA | UF::RO | UF::RR => 2,
+            UF::CE | UF::MA | UF::PI => 3,
+            UF::AL | UF::PB | UF::PE | UF::RN => 4,
+            UF::BA | UF::SE => 5,
+            UF::MG => 6,
+            UF::ES | UF::RJ => 7,
+            UF::SP => 8,
+            UF::PR | UF::SC => 9,
+        };
+
+        FiscalRegion { digit }
+    }
+
+    /// Ninth Cpf digit which encodes this fiscal region.
+    pub const fn digit(&self) -> u8 {
+        self.digit
+    }
+
+    /// Number of this fiscal region, in the range of `1..=10` (the 10th region is encoded by `0`).
+    pub const fn number(&self) -> u8 {
+        if self.digit == 0 {
+            10
         } else {
-            Err(CpfCreationError::InvalidCpfStringFormat)
+            self.digit
+        }
+    }
+
+    /// UFs under the jurisdiction of this fiscal region.
+    pub fn ufs(&self) -> &'static [UF] {
+        match self.digit {
+            0 => &[UF::RS],
+            1 => &[UF::DF, UF::GO, UF::MS, UF::MT, UF::TO],
+            2 => &[UF::AC, UF::AM, UF::AP, UF::PA, UF::RO, UF::RR],
+            3 => &[UF::CE, UF::MA, UF::PI],
+            4 => &[UF::AL, UF::PB, UF::PE, UF::RN],
+            5 => &[UF::BA, UF::SE],
+            6 => &[UF::MG],
+            7 => &[UF::ES, UF::RJ],
+            8 => &[UF::SP],
+            _ => &[UF::PR, UF::SC],
+        }
+    }
+
+    /// Checks whether `uf` is under the jurisdiction of this fiscal region.
+    pub fn contains(&self, uf: &UF) -> bool {
+        self.ufs().contains(uf)
+    }
+}
+
+/// Formats the fiscal region as its official name, for example: `8ª Região Fiscal`.
+impl fmt::Display for FiscalRegion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}ª Região Fiscal", self.number())
+    }
+}
+
+/// Checks whether all `digits` and `verifier_digits` are the same digit.
+const fn has_repeated_digits(digits: &[u8; 9], verifier_digits: &[u8; 2]) -> bool {
+    let mut pos = 0;
+    while pos < 9 {
+        if digits[pos] != digits[0] {
+            return false
         }
+        pos += 1;
     }
+
+    verifier_digits[0] == digits[0] && verifier_digits[1] == digits[0]
 }
 
 /// Calculates the verifier digit given input `cpf_digits`.
@@ -219,13 +618,14 @@ impl Cpf {
 /// assert_eq!(calculate_verifier_digit([4, 1, 4, 9, 0, 4, 2]), 8);
 /// ```
 ///
-pub fn calculate_verifier_digit<const S: usize>(cpf_digits: [u8; S]) -> u8 {
+pub const fn calculate_verifier_digit<const S: usize>(cpf_digits: [u8; S]) -> u8 {
     let modulo_num = S + 1;
-    let digits_sum: u16 = cpf_digits
-        .iter()
-        .enumerate()
-        .map(|(pos, digit)| (*digit as u16) * ((modulo_num - pos) as u16))
-        .sum();
+    let mut digits_sum: u16 = 0;
+    let mut pos = 0;
+    while pos < S {
+        digits_sum += (cpf_digits[pos] as u16) * ((modulo_num - pos) as u16);
+        pos += 1;
+    }
 
     let pre_verifier = ((digits_sum * 10) % 11) as u8;
     if pre_verifier == 10 {
@@ -246,11 +646,19 @@ pub fn calculate_verifier_digit<const S: usize>(cpf_digits: [u8; S]) -> u8 {
 ///
 /// assert_eq!(calculate_verifier_digits([4, 1, 4, 9, 0, 4, 2, 5, 7]), (8, 0));
 /// assert_eq!(calculate_verifier_digits([1, 2, 3, 4, 5, 6, 7, 8, 9]), (0, 9));
+///
+/// const VERIFIER_DIGITS: (u8, u8) = calculate_verifier_digits([4, 1, 4, 9, 0, 4, 2, 5, 7]);
+/// assert_eq!(VERIFIER_DIGITS, (8, 0));
 /// ```
-pub fn calculate_verifier_digits(digits: [u8; 9]) -> VerifierDigits {
+pub const fn calculate_verifier_digits(digits: [u8; 9]) -> VerifierDigits {
     let first_digit = calculate_verifier_digit::<9>(digits);
 
-    let digits_with_first_verifier: [u8; 10] = digits.append(first_digit);
+    let mut digits_with_first_verifier = [first_digit; 10];
+    let mut pos = 0;
+    while pos < 9 {
+        digits_with_first_verifier[pos] = digits[pos];
+        pos += 1;
+    }
     let second_digit = calculate_verifier_digit::<10>(digits_with_first_verifier);
 
     (first_digit, second_digit)
@@ -270,19 +678,68 @@ pub fn calculate_verifier_digits(digits: [u8; 9]) -> VerifierDigits {
 /// assert_eq!(verifier.1, cpf.verifier_digits[1]);
 /// ```
 #[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 impl Distribution<Cpf> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cpf {
-        let uniform_int = Uniform::from(0u8..=9u8);
-        let digits: Vec<u8> = rng.sample_iter(uniform_int)
-            .take(9)
-            .collect();
+        sample_cpf(rng, None)
+    }
+}
 
-        let digits_array: [u8; 9] = digits.try_into()
-            .expect("Conversion of Vec with 9 elements MUST be possible at this point.");
+/// ## Random CPF of a fiscal region Example
+///
+/// ```
+/// use validbr::Cpf;
+/// use validbr::cpf::FiscalRegion;
+/// use validbr::UF;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+///
+/// let cpf: Cpf = rng.sample(FiscalRegion::of(&UF::PR));
+/// assert!(cpf.fiscal_region().contains(&UF::SC));
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Cpf> for FiscalRegion {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cpf {
+        sample_cpf(rng, Some(self.digit))
+    }
+}
+
+/// ## Random CPF of an UF Example
+///
+/// ```
+/// use validbr::Cpf;
+/// use validbr::UF;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+///
+/// let cpf: Cpf = rng.sample(UF::SP);
+/// assert_eq!(cpf.digits[8], 8);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Cpf> for UF {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cpf {
+        FiscalRegion::of(self).sample(rng)
+    }
+}
+
+/// Samples a valid Cpf, with the ninth digit fixed to `fiscal_region_digit` if provided.
+#[cfg(feature = "rand")]
+fn sample_cpf<R: Rng + ?Sized>(rng: &mut R, fiscal_region_digit: Option<u8>) -> Cpf {
+    let uniform_int = Uniform::from(0u8..=9u8);
+    loop {
+        let mut digits_array: [u8; 9] = core::array::from_fn(|_| rng.sample(uniform_int));
+        if let Some(digit) = fiscal_region_digit {
+            digits_array[8] = digit;
+        }
 
         let (first, second) = calculate_verifier_digits(digits_array);
 
-        Cpf::new(digits_array, [first, second])
-            .expect("Generated Cpf MUST be valid at this point")
+        // Repeated digits Cpfs are known to be invalid, so they must be generated again.
+        if !has_repeated_digits(&digits_array, &[first, second]) {
+            return Cpf::new(digits_array, [first, second])
+                .expect("Generated Cpf MUST be valid at this point")
+        }
     }
 }
\ No newline at end of file
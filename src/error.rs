@@ -0,0 +1,240 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # Errors
+//!
+//! Every validbr error implements [`core::error::Error`] and renders an English message through
+//! [`Display`](core::fmt::Display). Messages could also be rendered in Brazilian Portuguese through
+//! [`LocalizedError::localized`](crate::error::LocalizedError::localized).
+//!
+//! # Example
+//!
+//! ```
+//! use validbr::Cpf;
+//! use validbr::error::{Language, LocalizedError};
+//!
+//! let error = Cpf::parse_str("111.111.111-11").unwrap_err();
+//! assert_eq!(error.to_string(), "invalid CPF: all digits are the same");
+//! assert_eq!(error.localized(Language::BrazilianPortuguese).to_string(), "CPF inválido: todos os dígitos são iguais");
+//! ```
+use core::fmt;
+use core::fmt::Formatter;
+
+/// Languages in which error messages could be rendered.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Language {
+    /// English, used by the [`Display`](fmt::Display) implementation of errors.
+    English,
+    /// Brazilian Portuguese (pt-BR).
+    BrazilianPortuguese,
+}
+
+/// English is the default language.
+impl Default for Language {
+    fn default() -> Self {
+        Language::English
+    }
+}
+
+/// Errors which messages could be rendered in any of the supported [`Language`]s.
+pub trait LocalizedError {
+    /// Writes the message of this error in the provided `language`.
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result;
+
+    /// Wraps this error in a [`Localized`], which [`Display`](fmt::Display) renders the message
+    /// of this error in the provided `language`.
+    fn localized(&self, language: Language) -> Localized<'_, Self> {
+        Localized {
+            error: self,
+            language,
+        }
+    }
+}
+
+/// Renders the message of the wrapped error in a specific [`Language`], see [`LocalizedError::localized`].
+#[derive(Debug, Clone, Copy)]
+pub struct Localized<'a, E: ?Sized> {
+    error: &'a E,
+    language: Language,
+}
+
+impl<E: LocalizedError + ?Sized> fmt::Display for Localized<'_, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.error.fmt_localized(f, self.language)
+    }
+}
+
+/// Where and why a document string did not match the expected format.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cpf;
+/// use validbr::cpf::CpfCreationError;
+/// use validbr::error::FormatMismatch;
+///
+/// let error = Cpf::parse_str("123.456.78x-09");
+/// assert_eq!(error, Err(CpfCreationError::InvalidCpfStringFormat(FormatMismatch { position: 10, found: Some('x'), input_length: 14 })));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct FormatMismatch {
+    /// Byte offset of the offending character in the input, or the input length when the input
+    /// ended before expected.
+    pub position: usize,
+    /// The offending character, or [`None`] when the input ended before expected.
+    pub found: Option<char>,
+    /// Length in bytes of the input.
+    pub input_length: usize,
+}
+
+impl LocalizedError for FormatMismatch {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match (self.found, language) {
+            (Some(c), Language::English) => {
+                write!(f, "unexpected character '{}' at position {}", c, self.position)
+            }
+            (Some(c), Language::BrazilianPortuguese) => {
+                write!(f, "caractere inesperado '{}' na posição {}", c, self.position)
+            }
+            (None, Language::English) => {
+                write!(f, "unexpected end of input at position {}", self.position)
+            }
+            (None, Language::BrazilianPortuguese) => {
+                write!(f, "fim inesperado na posição {}", self.position)
+            }
+        }
+    }
+}
+
+/// Expected and received verifier digits of a document which did not pass the verifier digits check.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cpf;
+/// use validbr::cpf::CpfCreationError;
+/// use validbr::error::{Language, LocalizedError, VerifierDigitsMismatch};
+///
+/// let error = Cpf::parse_str("123.456.789-10").unwrap_err();
+/// let mismatch = VerifierDigitsMismatch { expected: [0, 9], found: [1, 0] };
+/// assert_eq!(error, CpfCreationError::InvalidCpfDigits(mismatch));
+/// assert_eq!(mismatch.first_mismatch(), Some(0));
+/// assert_eq!(mismatch.localized(Language::BrazilianPortuguese).to_string(), "o dígito verificador deveria ser 09, encontrado 10");
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct VerifierDigitsMismatch<const N: usize> {
+    /// Verifier digits calculated from the document digits.
+    pub expected: [u8; N],
+    /// Verifier digits provided in the document.
+    pub found: [u8; N],
+}
+
+impl<const N: usize> VerifierDigitsMismatch<N> {
+    /// Index of the first verifier digit which does not match the expected one, or [`None`]
+    /// if all of them match.
+    pub fn first_mismatch(&self) -> Option<usize> {
+        self.expected
+            .iter()
+            .zip(self.found.iter())
+            .position(|(expected, found)| expected != found)
+    }
+}
+
+impl<const N: usize> LocalizedError for VerifierDigitsMismatch<N> {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match language {
+            Language::English => f.write_str("verifier digit should be ")?,
+            Language::BrazilianPortuguese => f.write_str("o dígito verificador deveria ser ")?,
+        }
+        write_digits(f, &self.expected)?;
+        match language {
+            Language::English => f.write_str(", found ")?,
+            Language::BrazilianPortuguese => f.write_str(", encontrado ")?,
+        }
+        write_digits(f, &self.found)
+    }
+}
+
+/// Writes each digit of `digits` without separators.
+fn write_digits(f: &mut Formatter<'_>, digits: &[u8]) -> fmt::Result {
+    for digit in digits {
+        write!(f, "{}", digit)?;
+    }
+    Ok(())
+}
+
+/// Implements [`Display`](fmt::Display) (in English) and [`core::error::Error`] for types
+/// implementing [`LocalizedError`].
+macro_rules! impl_error {
+    ($($error:ty),+ $(,)?) => {
+        $(
+            impl core::fmt::Display for $error {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    $crate::error::LocalizedError::fmt_localized(self, f, $crate::error::Language::English)
+                }
+            }
+
+            impl core::error::Error for $error {}
+        )+
+    };
+}
+
+pub(crate) use impl_error;
+
+impl_error!(FormatMismatch);
+
+impl<const N: usize> fmt::Display for VerifierDigitsMismatch<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_localized(f, Language::English)
+    }
+}
+
+impl<const N: usize> core::error::Error for VerifierDigitsMismatch<N> {}
+
+/// Writes the `message` followed by the `details` of an error, both in the provided `language`.
+pub(crate) fn write_with_details<D: LocalizedError>(
+    f: &mut Formatter<'_>,
+    language: Language,
+    english: &str,
+    portuguese: &str,
+    details: &D,
+) -> fmt::Result {
+    write_message(f, language, english, portuguese)?;
+    f.write_str(": ")?;
+    details.fmt_localized(f, language)
+}
+
+/// Writes the `english` or the `portuguese` message, according to the `language`.
+pub(crate) fn write_message(
+    f: &mut Formatter<'_>,
+    language: Language,
+    english: &str,
+    portuguese: &str,
+) -> fmt::Result {
+    match language {
+        Language::English => f.write_str(english),
+        Language::BrazilianPortuguese => f.write_str(portuguese),
+    }
+}
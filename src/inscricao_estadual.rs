@@ -0,0 +1,721 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # Inscrição Estadual
+//!
+//! This module provides utility for constructing and validating Inscrições Estaduais, which are
+//! issued by each [`UF`] with its own length, format and verifier digits algorithm, according to
+//! the specifications published by SINTEGRA. If an Inscrição Estadual was successfully
+//! constructed with [`InscricaoEstadual::new`] or [`InscricaoEstadual::parse_str`] it means that
+//! the Inscrição Estadual is valid.
+//!
+//! Contributors exempt from Inscrição Estadual are represented by [`InscricaoEstadual::Isento`],
+//! which is written as `ISENTO`, for example in NF-e.
+//!
+//! Only the Inscrições Estaduais of commerce and industry contributors are supported, the
+//! Inscrições Estaduais of rural producers of SP (`P-01100424.3/002`) are not.
+use crate::{InscricaoEstadual, UF};
+use crate::append::ArrayAppend;
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError, VerifierDigitsMismatch};
+use core::convert::TryInto;
+use core::fmt;
+use core::fmt::Formatter;
+
+/// Maximum number of digits of an Inscrição Estadual, which is the length of the RO one.
+const MAX_DIGITS: usize = 14;
+
+/// Text of the [`InscricaoEstadual::Isento`].
+const ISENTO: &str = "ISENTO";
+
+/// Digits of an Inscrição Estadual registered in an UF, see [`InscricaoEstadual::Inscrita`].
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Inscricao {
+    uf: UF,
+    digits: [u8; MAX_DIGITS],
+    length: u8,
+}
+
+impl Inscricao {
+    /// UF which issued this Inscrição Estadual.
+    pub fn uf(&self) -> UF {
+        self.uf
+    }
+
+    /// Digits of this Inscrição Estadual, including the verifier digits.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits[..usize::from(self.length)]
+    }
+}
+
+/// Formats the Inscrição Estadual with the official mask of its UF.
+impl fmt::Display for Inscricao {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let digits = self.digits();
+        let mask = IeRule::of(self.uf)
+            .masks
+            .iter()
+            .find(|mask| placeholders(mask) == digits.len())
+            .expect("Inscricao MUST have the length of one of the masks of its UF");
+
+        let mut digits = digits.iter();
+        for m in mask.chars() {
+            if m == '#' {
+                write!(f, "{}", digits.next().expect("Mask MUST have one placeholder per digit"))?;
+            } else {
+                write!(f, "{}", m)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats the Inscrição Estadual with the official mask of its UF, or `ISENTO`.
+///
+/// # Example
+///
+/// ```
+/// use validbr::{InscricaoEstadual, UF};
+/// let ie = InscricaoEstadual::parse_str(UF::SP, "110042490114").expect("Invalid inscrição estadual.");
+/// assert_eq!(format!("{}", ie), "110.042.490.114");
+/// assert_eq!(format!("{}", InscricaoEstadual::Isento), "ISENTO");
+/// ```
+impl fmt::Display for InscricaoEstadual {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InscricaoEstadual::Inscrita(inscricao) => fmt::Display::fmt(inscricao, f),
+            InscricaoEstadual::Isento => f.write_str(ISENTO),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum InscricaoEstadualCreationError {
+    /// When provided Inscrição Estadual digits could not be validated against its verifier digit,
+    /// for UFs which have only one verifier digit.
+    ///
+    /// Holds the expected and the provided verifier digit.
+    InvalidVerifierDigit(VerifierDigitsMismatch<1>),
+    /// When provided Inscrição Estadual digits could not be validated against its verifier digits,
+    /// for UFs which have two verifier digits (AC, BA, DF, MG, PE, PR and SP).
+    ///
+    /// Holds the expected and the provided verifier digits.
+    InvalidVerifierDigits(VerifierDigitsMismatch<2>),
+    /// When the digits which have fixed values in the UF do not match them, such as the `24`
+    /// prefix of AL, or the `01`, `02`, `03` or `99` third and fourth digits of the 11 digits TO
+    /// Inscrição Estadual.
+    InvalidFixedDigits,
+    /// When provided Inscrição Estadual does not have one of the lengths of the UF.
+    InvalidInscricaoEstadualLength {
+        /// Lengths of the Inscrições Estaduais of the UF.
+        expected: &'static [usize],
+        /// Provided length.
+        found: usize,
+    },
+    /// When provided Inscrição Estadual string is neither in one of the formats of the UF nor
+    /// `ISENTO`.
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidInscricaoEstadualStringFormat(FormatMismatch),
+    /// When provided Inscrição Estadual string has less digits than the Inscrições Estaduais of
+    /// the UF.
+    ShortInscricaoEstadualString(FormatMismatch),
+    /// When provided numbers for digits are out of bounds, in other words, they are not
+    /// respecting the range of `0..=9`.
+    DigitsOutOfBounds,
+}
+
+impl LocalizedError for InscricaoEstadualCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            InscricaoEstadualCreationError::InvalidVerifierDigit(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid inscrição estadual",
+                "inscrição estadual inválida",
+                mismatch,
+            ),
+            InscricaoEstadualCreationError::InvalidVerifierDigits(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid inscrição estadual",
+                "inscrição estadual inválida",
+                mismatch,
+            ),
+            InscricaoEstadualCreationError::InvalidFixedDigits => write_message(
+                f,
+                language,
+                "invalid inscrição estadual: digits do not match the fixed digits of the UF",
+                "inscrição estadual inválida: os dígitos não correspondem aos dígitos fixos da UF",
+            ),
+            InscricaoEstadualCreationError::InvalidInscricaoEstadualLength { expected, found } => {
+                write_message(
+                    f,
+                    language,
+                    "inscrição estadual must have ",
+                    "a inscrição estadual deve ter ",
+                )?;
+                for (i, length) in expected.iter().enumerate() {
+                    if i > 0 {
+                        write_message(f, language, " or ", " ou ")?;
+                    }
+                    write!(f, "{}", length)?;
+                }
+                match language {
+                    Language::English => write!(f, " digits, found {}", found),
+                    Language::BrazilianPortuguese => write!(f, " dígitos, encontrado {}", found),
+                }
+            }
+            InscricaoEstadualCreationError::InvalidInscricaoEstadualStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid inscrição estadual format",
+                "formato de inscrição estadual inválido",
+                mismatch,
+            ),
+            InscricaoEstadualCreationError::ShortInscricaoEstadualString(mismatch) => write_with_details(
+                f,
+                language,
+                "inscrição estadual has less digits than expected",
+                "inscrição estadual possui menos dígitos que o esperado",
+                mismatch,
+            ),
+            InscricaoEstadualCreationError::DigitsOutOfBounds => write_message(
+                f,
+                language,
+                "inscrição estadual digits must be in the range of 0 to 9",
+                "os dígitos da inscrição estadual devem estar entre 0 e 9",
+            ),
+        }
+    }
+}
+
+impl_error!(InscricaoEstadualCreationError);
+
+/// Validates the fixed digits and the verifier digits of an Inscrição Estadual.
+type Validator = fn(&[u8]) -> Result<(), InscricaoEstadualCreationError>;
+
+/// Formats and validation of the Inscrições Estaduais issued by an UF.
+#[derive(Clone, Copy)]
+struct IeRule {
+    /// Official masks of the Inscrições Estaduais, one per supported length, in which `#` is a
+    /// placeholder for a digit, see [`parse::normalize`].
+    masks: &'static [&'static str],
+    /// Lengths of the Inscrições Estaduais, in the same order as `masks`.
+    lengths: &'static [usize],
+    /// Validates the fixed digits and the verifier digits, the length is validated beforehand.
+    validate: Validator,
+}
+
+impl IeRule {
+    fn of(uf: UF) -> IeRule {
+        let (masks, lengths, validate): (&'static [&'static str], &'static [usize], Validator) = match uf {
+            UF::AC => (&["##.###.###/###-##"], &[13], validate_ac),
+            UF::AL => (&["#########"], &[9], validate_al),
+            UF::AP => (&["#########"], &[9], validate_ap),
+            UF::AM => (&["##.###.###-#"], &[9], validate_am),
+            UF::BA => (&["######-##", "#######-##"], &[8, 9], validate_ba),
+            UF::CE => (&["########-#"], &[9], validate_modulo11),
+            UF::DF => (&["##.######.###-##"], &[13], validate_df),
+            UF::ES => (&["###.###.##-#"], &[9], validate_modulo11),
+            UF::GO => (&["##.###.###-#"], &[9], validate_go),
+            UF::MA => (&["#########"], &[9], validate_ma),
+            UF::MT => (&["##########-#"], &[11], validate_mt),
+            UF::MS => (&["#########"], &[9], validate_ms),
+            UF::MG => (&["###.###.###/####"], &[13], validate_mg),
+            UF::PA => (&["##-######-#"], &[9], validate_pa),
+            UF::PB => (&["########-#"], &[9], validate_modulo11),
+            UF::PR => (&["###.#####-##"], &[10], validate_pr),
+            UF::PE => (&["#######-##"], &[9], validate_pe),
+            UF::PI => (&["#########"], &[9], validate_modulo11),
+            UF::RJ => (&["##.###.##-#"], &[8], validate_rj),
+            UF::RN => (&["##.###.###-#", "##.#.###.###-#"], &[9, 10], validate_rn),
+            UF::RS => (&["###/#######"], &[10], validate_rs),
+            UF::RO => (&["#############-#"], &[14], validate_ro),
+            UF::RR => (&["########-#"], &[9], validate_rr),
+            UF::SC => (&["###.###.###"], &[9], validate_modulo11),
+            UF::SP => (&["###.###.###.###"], &[12], validate_sp),
+            UF::SE => (&["########-#"], &[9], validate_modulo11),
+            UF::TO => (&["#########", "##.##.######-#"], &[9, 11], validate_to),
+        };
+
+        IeRule { masks, lengths, validate }
+    }
+}
+
+/// Number of digit placeholders of `mask`.
+fn placeholders(mask: &str) -> usize {
+    mask.bytes().filter(|m| *m == b'#').count()
+}
+
+impl InscricaoEstadual {
+    /// Creates a new Inscrição Estadual issued by the `uf` if the provided `digits` are valid
+    /// according to the length, fixed digits and verifier digits of the UF.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::{InscricaoEstadual, UF};
+    /// use validbr::inscricao_estadual::InscricaoEstadualCreationError;
+    /// use validbr::error::VerifierDigitsMismatch;
+    ///
+    /// let ie = InscricaoEstadual::new(UF::RS, &[2, 2, 4, 3, 6, 5, 8, 7, 9, 2]); // Valid inscrição estadual
+    /// assert!(ie.is_ok());
+    ///
+    /// let ie = InscricaoEstadual::new(UF::RS, &[2, 2, 4, 3, 6, 5, 8, 7, 9, 3]); // Invalid inscrição estadual
+    /// assert_eq!(ie, Err(InscricaoEstadualCreationError::InvalidVerifierDigit(VerifierDigitsMismatch { expected: [2], found: [3] })));
+    ///
+    /// let ie = InscricaoEstadual::new(UF::RS, &[2, 2, 4, 3, 6, 5, 8, 7, 9]);
+    /// assert_eq!(ie, Err(InscricaoEstadualCreationError::InvalidInscricaoEstadualLength { expected: &[10], found: 9 }));
+    /// ```
+    pub fn new(uf: UF, digits: &[u8]) -> Result<InscricaoEstadual, InscricaoEstadualCreationError> {
+        let rule = IeRule::of(uf);
+        if !rule.lengths.contains(&digits.len()) {
+            return Err(InscricaoEstadualCreationError::InvalidInscricaoEstadualLength {
+                expected: rule.lengths,
+                found: digits.len(),
+            })
+        }
+
+        if !digits.iter().all(|d| *d <= 9) {
+            return Err(InscricaoEstadualCreationError::DigitsOutOfBounds)
+        }
+
+        (rule.validate)(digits)?;
+
+        let mut inscricao = Inscricao {
+            uf,
+            digits: [0; MAX_DIGITS],
+            length: digits.len() as u8,
+        };
+        inscricao.digits[..digits.len()].copy_from_slice(digits);
+
+        Ok(InscricaoEstadual::Inscrita(inscricao))
+    }
+
+    /// Parses an Inscrição Estadual String issued by the `uf` to an [`InscricaoEstadual`].
+    ///
+    /// Supported formats are the official masks of each UF, the only digits representation and
+    /// `ISENTO`:
+    ///
+    /// | UF | Formats                                 |
+    /// |----|-----------------------------------------|
+    /// | AC | `##.###.###/###-##`                     |
+    /// | AL | `#########`                             |
+    /// | AP | `#########`                             |
+    /// | AM | `##.###.###-#`                          |
+    /// | BA | `######-##` or `#######-##`             |
+    /// | CE | `########-#`                            |
+    /// | DF | `##.######.###-##`                      |
+    /// | ES | `###.###.##-#`                          |
+    /// | GO | `##.###.###-#`                          |
+    /// | MA | `#########`                             |
+    /// | MT | `##########-#`                          |
+    /// | MS | `#########`                             |
+    /// | MG | `###.###.###/####`                      |
+    /// | PA | `##-######-#`                           |
+    /// | PB | `########-#`                            |
+    /// | PR | `###.#####-##`                          |
+    /// | PE | `#######-##`                            |
+    /// | PI | `#########`                             |
+    /// | RJ | `##.###.##-#`                           |
+    /// | RN | `##.###.###-#` or `##.#.###.###-#`      |
+    /// | RS | `###/#######`                           |
+    /// | RO | `#############-#`                       |
+    /// | RR | `########-#`                            |
+    /// | SC | `###.###.###`                           |
+    /// | SP | `###.###.###.###`                       |
+    /// | SE | `########-#`                            |
+    /// | TO | `#########` or `##.##.######-#`         |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{InscricaoEstadual, UF};
+    ///
+    /// let ie = InscricaoEstadual::parse_str(UF::MG, "062.307.904/0081").unwrap();
+    /// assert_eq!(InscricaoEstadual::parse_str(UF::MG, "0623079040081"), Ok(ie));
+    /// assert_eq!(InscricaoEstadual::parse_str(UF::MG, "ISENTO"), Ok(InscricaoEstadual::Isento));
+    /// ```
+    pub fn parse_str(uf: UF, inscricao_estadual: &str) -> Result<InscricaoEstadual, InscricaoEstadualCreationError> {
+        InscricaoEstadual::parse_str_with(uf, inscricao_estadual, &ParseOptions::strict())
+    }
+
+    /// Parses an Inscrição Estadual String issued by the `uf` to an [`InscricaoEstadual`]
+    /// according to the provided parse `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option, lenient parsing
+    /// also accepts `ISENTO` in any case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{InscricaoEstadual, UF};
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let ie = InscricaoEstadual::parse_str_with(UF::MT, "130000019", &ParseOptions::padded());
+    /// assert_eq!(ie, InscricaoEstadual::parse_str(UF::MT, "0013000001-9"));
+    /// assert_eq!(InscricaoEstadual::parse_str_with(UF::MT, " Isento ", &ParseOptions::lenient()), Ok(InscricaoEstadual::Isento));
+    /// ```
+    pub fn parse_str_with(
+        uf: UF,
+        inscricao_estadual: &str,
+        options: &ParseOptions,
+    ) -> Result<InscricaoEstadual, InscricaoEstadualCreationError> {
+        let isento = if options.lenient {
+            inscricao_estadual.trim().eq_ignore_ascii_case(ISENTO)
+        } else {
+            inscricao_estadual == ISENTO
+        };
+        if isento {
+            return Ok(InscricaoEstadual::Isento)
+        }
+
+        let mut normalized = [0u8; MAX_DIGITS];
+        let mut error = None;
+        for mask in IeRule::of(uf).masks.iter() {
+            let normalized = &mut normalized[..placeholders(mask)];
+            match parse::normalize_into(inscricao_estadual, options, mask, normalized) {
+                Ok(()) => {
+                    normalized.iter_mut().for_each(|c| *c -= b'0');
+                    return InscricaoEstadual::new(uf, normalized)
+                }
+                Err(e) => error = Some(e),
+            }
+        }
+
+        Err(match error.expect("Every UF MUST have at least one mask") {
+            FormatError::InvalidFormat(mismatch) => InscricaoEstadualCreationError::InvalidInscricaoEstadualStringFormat(mismatch),
+            FormatError::Short(mismatch) => InscricaoEstadualCreationError::ShortInscricaoEstadualString(mismatch),
+        })
+    }
+
+    /// UF which issued this Inscrição Estadual, or [`None`] for [`InscricaoEstadual::Isento`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{InscricaoEstadual, UF};
+    ///
+    /// assert_eq!(InscricaoEstadual::parse_str(UF::RJ, "99.999.99-3").unwrap().uf(), Some(UF::RJ));
+    /// assert_eq!(InscricaoEstadual::Isento.uf(), None);
+    /// ```
+    pub fn uf(&self) -> Option<UF> {
+        match self {
+            InscricaoEstadual::Inscrita(inscricao) => Some(inscricao.uf()),
+            InscricaoEstadual::Isento => None,
+        }
+    }
+
+    /// Checks whether this is [`InscricaoEstadual::Isento`].
+    pub fn is_isento(&self) -> bool {
+        matches!(self, InscricaoEstadual::Isento)
+    }
+}
+
+/// Sum of the `digits` multiplied by the weights from `digits.len() + 1` to `2`.
+fn sum_descending(digits: &[u8]) -> u32 {
+    digits.iter().rev().enumerate().map(|(i, d)| u32::from(*d) * (i as u32 + 2)).sum()
+}
+
+/// Sum of the `digits` multiplied by the weights from `2` to `max_weight`, restarting at `2`
+/// after `max_weight`, from the rightmost digit.
+fn sum_cyclic(digits: &[u8], max_weight: u32) -> u32 {
+    digits.iter().rev().enumerate().map(|(i, d)| u32::from(*d) * (i as u32 % (max_weight - 1) + 2)).sum()
+}
+
+/// Verifier digit of the modulo 11 algorithm used by most UFs: `0` if the remainder `r` of the
+/// division of `sum` by 11 is `0` or `1`, `11 - r` otherwise.
+fn modulo11(sum: u32) -> u8 {
+    match sum % 11 {
+        0 | 1 => 0,
+        remainder => 11 - remainder as u8,
+    }
+}
+
+/// Compares the last digit of `digits` with the `expected` verifier digit.
+fn check_digit(digits: &[u8], expected: u8) -> Result<(), InscricaoEstadualCreationError> {
+    let found = digits[digits.len() - 1];
+    if expected == found {
+        Ok(())
+    } else {
+        Err(InscricaoEstadualCreationError::InvalidVerifierDigit(VerifierDigitsMismatch {
+            expected: [expected],
+            found: [found],
+        }))
+    }
+}
+
+/// Compares the `positions` of `digits` with the `expected` verifier digits.
+fn check_digits(digits: &[u8], positions: [usize; 2], expected: [u8; 2]) -> Result<(), InscricaoEstadualCreationError> {
+    let found = [digits[positions[0]], digits[positions[1]]];
+    if expected == found {
+        Ok(())
+    } else {
+        Err(InscricaoEstadualCreationError::InvalidVerifierDigits(VerifierDigitsMismatch { expected, found }))
+    }
+}
+
+/// Checks whether the first two digits are one of the `prefixes`.
+fn check_prefix(digits: &[u8], prefixes: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    if prefixes.contains(&(digits[0] * 10 + digits[1])) {
+        Ok(())
+    } else {
+        Err(InscricaoEstadualCreationError::InvalidFixedDigits)
+    }
+}
+
+/// Value of the `digits` as a number.
+fn number(digits: &[u8]) -> u32 {
+    digits.iter().fold(0, |acc, d| acc * 10 + u32::from(*d))
+}
+
+/// Single verifier digit calculated with [`modulo11`] over the descending weights, used by CE,
+/// ES, PB, PI, SC and SE.
+fn validate_modulo11(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    let (_, base) = digits.split_last().expect("Length MUST be validated at this point");
+    check_digit(digits, modulo11(sum_descending(base)))
+}
+
+/// Converts the first `N` digits to an array.
+fn first<const N: usize>(digits: &[u8]) -> [u8; N] {
+    digits[..N].try_into().expect("Length MUST be validated at this point")
+}
+
+/// Two verifier digits calculated with [`modulo11`] over the weights from 2 to 9, used by AC
+/// and DF.
+fn validate_two_cyclic(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    let base: [u8; 11] = first(digits);
+    let first_digit = modulo11(sum_cyclic(&base, 9));
+    let second_digit = modulo11(sum_cyclic(&base.append::<12>(first_digit), 9));
+    check_digits(digits, [11, 12], [first_digit, second_digit])
+}
+
+/// AC: 13 digits starting with `01`.
+fn validate_ac(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[1])?;
+    validate_two_cyclic(digits)
+}
+
+/// DF: 13 digits starting with `07`.
+fn validate_df(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[7])?;
+    validate_two_cyclic(digits)
+}
+
+/// AL: 9 digits starting with `24`.
+fn validate_al(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[24])?;
+    validate_modulo11(digits)
+}
+
+/// AP: 9 digits starting with `03`, which sum is incremented according to the range of the number.
+fn validate_ap(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[3])?;
+    let base: [u8; 8] = first(digits);
+    let (p, d) = match number(&base) {
+        3_000_001..=3_017_000 => (5, 0),
+        3_017_001..=3_019_022 => (9, 1),
+        _ => (0, 0),
+    };
+
+    let expected = match 11 - (p + sum_descending(&base)) % 11 {
+        10 => 0,
+        11 => d,
+        digit => digit as u8,
+    };
+    check_digit(digits, expected)
+}
+
+/// AM: 9 digits, sums lower than 11 are subtracted from 11 instead of their remainder, and the
+/// differences `10` and `11` are replaced by `0`.
+fn validate_am(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    let sum = sum_descending(&digits[..8]);
+    let expected = match sum {
+        0 | 1 => 0,
+        2..=10 => 11 - sum as u8,
+        _ => modulo11(sum),
+    };
+    check_digit(digits, expected)
+}
+
+/// BA: 8 or 9 digits, validated with modulo 10 or 11 according to the first digit (or the second
+/// digit of 9 digits Inscrições Estaduais). The last verifier digit is calculated first, and is
+/// included in the calculation of the other one.
+fn validate_ba(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    let length = digits.len();
+    let modulo10 = matches!(digits[length - 8], 0..=5 | 8);
+    let verifier_digit = |sum: u32| {
+        if modulo10 {
+            ((10 - sum % 10) % 10) as u8
+        } else {
+            modulo11(sum)
+        }
+    };
+
+    let (second, first_digit) = if length == 8 {
+        let base: [u8; 6] = first(digits);
+        let second = verifier_digit(sum_descending(&base));
+        (second, verifier_digit(sum_descending(&base.append::<7>(second))))
+    } else {
+        let base: [u8; 7] = first(digits);
+        let second = verifier_digit(sum_descending(&base));
+        (second, verifier_digit(sum_descending(&base.append::<8>(second))))
+    };
+    check_digits(digits, [length - 2, length - 1], [first_digit, second])
+}
+
+/// GO: 9 digits starting with `10`, `11`, `15` or `20` to `29`, with exceptions for the
+/// remainder 1.
+fn validate_go(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[10, 11, 15, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29])?;
+    let base: [u8; 8] = first(digits);
+    let number = number(&base);
+    if number == 11_094_402 && digits[8] <= 1 {
+        // Both 0 and 1 are accepted as the verifier digit of this number.
+        return Ok(())
+    }
+
+    let expected = match sum_descending(&base) % 11 {
+        0 => 0,
+        1 if (10_103_105..=10_119_997).contains(&number) => 1,
+        1 => 0,
+        remainder => 11 - remainder as u8,
+    };
+    check_digit(digits, expected)
+}
+
+/// MA: 9 digits starting with `12`.
+fn validate_ma(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[12])?;
+    validate_modulo11(digits)
+}
+
+/// MT: 11 digits with weights from 2 to 9.
+fn validate_mt(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_digit(digits, modulo11(sum_cyclic(&digits[..10], 9)))
+}
+
+/// MS: 9 digits starting with `28` or `50`.
+fn validate_ms(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[28, 50])?;
+    validate_modulo11(digits)
+}
+
+/// MG: 13 digits, the first verifier digit is the complement to the next multiple of 10 of the
+/// sum of the digits of the products of the digits (with a `0` inserted after the third one) by
+/// `1` and `2` alternately, and the second one is calculated with [`modulo11`] over the weights
+/// from 2 to 11.
+fn validate_mg(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    let base: [u8; 11] = first(digits);
+    let sum: u32 = base[..3]
+        .iter()
+        .chain(core::iter::once(&0))
+        .chain(base[3..].iter())
+        .enumerate()
+        .map(|(i, d)| u32::from(*d) * (i as u32 % 2 + 1))
+        .map(|product| product / 10 + product % 10)
+        .sum();
+    let first_digit = ((10 - sum % 10) % 10) as u8;
+    let second_digit = modulo11(sum_cyclic(&base.append::<12>(first_digit), 11));
+    check_digits(digits, [11, 12], [first_digit, second_digit])
+}
+
+/// PA: 9 digits starting with `15`.
+fn validate_pa(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[15])?;
+    validate_modulo11(digits)
+}
+
+/// PR: 10 digits, two verifier digits over the weights from 2 to 7.
+fn validate_pr(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    let base: [u8; 8] = first(digits);
+    let first_digit = modulo11(sum_cyclic(&base, 7));
+    let second_digit = modulo11(sum_cyclic(&base.append::<9>(first_digit), 7));
+    check_digits(digits, [8, 9], [first_digit, second_digit])
+}
+
+/// PE: 9 digits, two verifier digits over the descending weights.
+fn validate_pe(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    let base: [u8; 7] = first(digits);
+    let first_digit = modulo11(sum_descending(&base));
+    let second_digit = modulo11(sum_descending(&base.append::<8>(first_digit)));
+    check_digits(digits, [7, 8], [first_digit, second_digit])
+}
+
+/// RJ: 8 digits with weights from 2 to 7.
+fn validate_rj(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_digit(digits, modulo11(sum_cyclic(&digits[..7], 7)))
+}
+
+/// RN: 9 or 10 digits starting with `20`.
+fn validate_rn(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[20])?;
+    validate_modulo11(digits)
+}
+
+/// RS: 10 digits with weights from 2 to 9.
+fn validate_rs(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_digit(digits, modulo11(sum_cyclic(&digits[..9], 9)))
+}
+
+/// RO: 14 digits with weights from 2 to 9, where `10` and `11` are replaced by `0` and `1`.
+fn validate_ro(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    let expected = (11 - sum_cyclic(&digits[..13], 9) % 11) % 10;
+    check_digit(digits, expected as u8)
+}
+
+/// RR: 9 digits starting with `24`, with the remainder of the division by 9 of the sum over
+/// the weights from 1 to 8.
+fn validate_rr(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    check_prefix(digits, &[24])?;
+    let sum: u32 = digits[..8].iter().enumerate().map(|(i, d)| u32::from(*d) * (i as u32 + 1)).sum();
+    check_digit(digits, (sum % 9) as u8)
+}
+
+/// SP: 12 digits, the ninth and the twelfth are verifier digits, which are the rightmost digit of
+/// the remainder of the division by 11 of the weighted sums.
+fn validate_sp(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    const FIRST_WEIGHTS: [u32; 8] = [1, 3, 4, 5, 6, 7, 8, 10];
+    const SECOND_WEIGHTS: [u32; 11] = [3, 2, 10, 9, 8, 7, 6, 5, 4, 3, 2];
+    let weighted = |weights: &[u32]| -> u32 { digits.iter().zip(weights).map(|(d, w)| u32::from(*d) * w).sum() };
+
+    let first_digit = (weighted(&FIRST_WEIGHTS) % 11 % 10) as u8;
+    let second_digit = (weighted(&SECOND_WEIGHTS) % 11 % 10) as u8;
+    check_digits(digits, [8, 11], [first_digit, second_digit])
+}
+
+/// TO: 9 digits, or 11 digits with the kind of contributor (`01`, `02`, `03` or `99`) in the third
+/// and fourth digits, which are ignored by the verifier digit.
+fn validate_to(digits: &[u8]) -> Result<(), InscricaoEstadualCreationError> {
+    let base: [u8; 8] = if digits.len() == 11 {
+        if !matches!(number(&digits[2..4]), 1..=3 | 99) {
+            return Err(InscricaoEstadualCreationError::InvalidFixedDigits)
+        }
+        first::<2>(digits).append_array(first::<6>(&digits[4..]))
+    } else {
+        first(digits)
+    };
+    check_digit(digits, modulo11(sum_descending(&base)))
+}
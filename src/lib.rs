@@ -47,6 +47,9 @@
 //! - `###.###.###-##` (Commonly represented CPF)
 //! - `###########` (Only digits CPF).
 //!
+//! [`Cpf::parse_str_with`] also supports formatting noise and missing leading zeros,
+//! see [`parse::ParseOptions`].
+//!
 //! # CNPJ
 //!
 //! Consists in eight numbers separated by a `.` in partitions for 3 (except for the first two digits
@@ -61,11 +64,176 @@
 //! assert_eq!(cpf, Ok(Cnpj { digits: [1, 2, 3, 4, 5, 6, 7, 8], branch_digits: [0, 0, 0, 1], verifier_digits: [9, 5]}));
 //! ```
 //!
+//! ## Alphanumeric CNPJ
+//!
+//! Alphanumeric CNPJ allows uppercase letters in the root and branch positions, for example:
+//! `12.ABC.345/01DE-35`. Letters are stored as their ASCII code minus 48 (see [`cnpj::char_to_value`]).
+//!
+//! ```
+//! use validbr::Cnpj;
+//! let cnpj = Cnpj::parse_str("12.ABC.345/01DE-35");
+//! assert_eq!(cnpj, Ok(Cnpj { digits: [1, 2, 17, 18, 19, 3, 4, 5], branch_digits: [0, 1, 20, 21], verifier_digits: [3, 5]}));
+//! ```
+//!
 //! ## Supported formats
 //!
 //! [`Cnpj::parse_str`] only supports following formats:
 //! - `##.###.###/####-##` (Commonly represented CNPJ)
 //! - `##############` (Only digits CNPJ).
+//! - `AA.AAA.AAA/AAAA-##` (Alphanumeric CNPJ)
+//! - `AAAAAAAAAAAA##` (Only characters alphanumeric CNPJ).
+//!
+//! [`Cnpj::parse_str_with`] also supports formatting noise and missing leading zeros,
+//! see [`parse::ParseOptions`].
+//!
+//! # CNH
+//!
+//! Consists in 9 digits and two verifier digits, without any separator, for example: `02978515070`.
+//! Both the current and the legacy DENATRAN verifier digits algorithms are supported.
+//!
+//! ## Example of usage of CNH struct
+//!
+//! ```
+//! use validbr::Cnh;
+//! let cnh = Cnh::parse_str("02978515070");
+//! assert_eq!(cnh, Ok(Cnh { digits: [0, 2, 9, 7, 8, 5, 1, 5, 0], verifier_digits: [7, 0]}));
+//! ```
+//!
+//! The category and dates of the driver's license are represented by [`cnh::CnhDetails`].
+//!
+//! # RENAVAM
+//!
+//! Consists in 11 digits, for example: `00639884962`. Legacy RENAVAM with 9 digits are padded
+//! with two leading zeros.
+//!
+//! ```
+//! use validbr::Renavam;
+//! let renavam = Renavam::parse_str("639884962");
+//! assert_eq!(renavam, Ok(Renavam { digits: [0, 0, 6, 3, 9, 8, 8, 4, 9, 6], verifier_digit: 2 }));
+//! ```
+//!
+//! # Placa
+//!
+//! Vehicle license plates in the old format, such as `ABC-1234`, and in the Mercosul format, such
+//! as `ABC1C34`, are converted to each other with [`Placa::to_mercosul`] and [`Placa::to_old`].
+//!
+//! ```
+//! use validbr::{Placa, UF};
+//! let placa = Placa::parse_str("KMF-1234").unwrap();
+//! assert_eq!(placa.to_mercosul().to_string(), "KMF1C34");
+//! assert_eq!(placa.uf(), Some(UF::RJ));
+//! ```
+//!
+//! # PIS
+//!
+//! Consists in 11 digits, formatted as `###.#####.##-#`, for example: `120.12345.67-2`. The same
+//! number is used by PASEP, NIT and NIS.
+//!
+//! ```
+//! use validbr::Pis;
+//! let pis = Pis::parse_str("120.12345.67-2");
+//! assert_eq!(pis, Ok(Pis { digits: [1, 2, 0, 1, 2, 3, 4, 5, 6, 7], verifier_digit: 2 }));
+//! ```
+//!
+//! # CNS
+//!
+//! Consists in 15 digits, usually grouped as `### #### #### ####`, for example:
+//! `898 0010 0000 9310`. CNS starting with `1` or `2` are definitive and derived from the PIS,
+//! while CNS starting with `7`, `8` or `9` are provisional, see [`cns::CnsKind`].
+//!
+//! ```
+//! use validbr::Cns;
+//! use validbr::cns::CnsKind;
+//! let cns = Cns::parse_str("898 0010 0000 9310").unwrap();
+//! assert_eq!(cns.kind(), CnsKind::Provisional);
+//! ```
+//!
+//! # Título de Eleitor
+//!
+//! Consists in 12 digits, usually grouped by 4, for example: `1023 8501 0671`. Digits 9 and 10
+//! identify the UF where the voter is registered, or `28` for voters registered abroad.
+//!
+//! ```
+//! use validbr::{TituloEleitor, UF};
+//! let titulo = TituloEleitor::parse_str("1023 8501 0671").unwrap();
+//! assert_eq!(titulo.uf(), Some(UF::PR));
+//! ```
+//!
+//! # Inscrição Estadual
+//!
+//! Each UF has its own length, format and verifier digits algorithm, so Inscrições Estaduais are
+//! parsed with the UF which issued them. Contributors exempt from Inscrição Estadual are
+//! represented by [`InscricaoEstadual::Isento`].
+//!
+//! ```
+//! use validbr::{InscricaoEstadual, UF};
+//! let ie = InscricaoEstadual::parse_str(UF::SP, "110.042.490.114").unwrap();
+//! assert_eq!(ie.uf(), Some(UF::SP));
+//! assert_eq!(InscricaoEstadual::parse_str(UF::SP, "ISENTO"), Ok(InscricaoEstadual::Isento));
+//! ```
+//!
+//! # CEP
+//!
+//! Consists in 8 digits with the last three separated by a `-` prefix, for example: `01310-100`.
+//! The UF a CEP belongs to is resolved through the CEP ranges assigned by Correios to each UF.
+//!
+//! ```
+//! use validbr::{Cep, UF};
+//! let cep = Cep::parse_str("01310-100").unwrap();
+//! assert_eq!(cep.uf(), Some(UF::SP));
+//! ```
+//!
+//! # Municipios
+//!
+//! Municipalities are identified by the 7 digits IBGE code, which verifier digit is validated
+//! when resolving a [`Municipio`] from the bundled table:
+//!
+//! ```
+//! use validbr::{Municipio, UF};
+//! let municipio = Municipio::from_code(3304557).unwrap();
+//! assert_eq!(municipio.to_string(), "Rio de Janeiro - RJ");
+//! assert_eq!(Municipio::find(&UF::RJ, "rio de janeiro"), Some(municipio));
+//! ```
+//!
+//! # UF
+//!
+//! [`UF`] provides the names, IBGE codes, macro-regions and capitals of the Brazilian states,
+//! and could be parsed from either the abbreviation or the name of the state:
+//!
+//! ```
+//! use validbr::UF;
+//! use validbr::uf::Regiao;
+//! let uf: UF = "Sao Paulo".parse().unwrap();
+//! assert_eq!(uf, UF::SP);
+//! assert_eq!((uf.name(), uf.ibge_code(), uf.regiao()), ("São Paulo", 35, Regiao::Sudeste));
+//! assert_eq!(uf.capital().name, "São Paulo");
+//! ```
+//!
+//! # Compile-time validated literals
+//!
+//! Known Cpfs and Cnpjs could be validated at compile time through the [`cpf!`] and [`cnpj!`]
+//! macros, which accept the same formats as `parse_str`:
+//!
+//! ```
+//! use validbr::{cnpj, cpf, Cnpj, Cpf};
+//!
+//! const CPF: Cpf = cpf!("123.456.789-09");
+//! const CNPJ: Cnpj = cnpj!("12.345.678/0001-95");
+//! ```
+//!
+//! # Errors
+//!
+//! All errors implement [`core::error::Error`] with English messages, which could also be rendered
+//! in Brazilian Portuguese through [`error::LocalizedError`]:
+//!
+//! ```
+//! use validbr::Cpf;
+//! use validbr::error::{Language, LocalizedError};
+//!
+//! let error = Cpf::parse_str("123.456.789-10").unwrap_err();
+//! assert_eq!(error.to_string(), "invalid CPF: verifier digit should be 09, found 10");
+//! assert_eq!(error.localized(Language::BrazilianPortuguese).to_string(), "CPF inválido: o dígito verificador deveria ser 09, encontrado 10");
+//! ```
 //!
 //! # Features
 //!
@@ -78,6 +246,9 @@
 //! validbr = { version = "0.2", features = ["serde"] }
 //! ```
 //!
+//! Documents are serialized as strings and validated when deserialized, see `serde_format`
+//! module for the available representations.
+//!
 //! ## [rand](https://crates.io/crates/rand) support
 //!
 //! validbr also supports randomly generated CPF and CNPJ through [rand](https://crates.io/crates/serde) crate,
@@ -88,6 +259,33 @@
 //! validbr = { version = "0.2", features = ["rand"] }
 //! ```
 //!
+//! ## `no_std` support
+//!
+//! CPF and CNPJ creation, parsing, formatting and validation do not require the standard library
+//! nor heap allocation. The `std` feature is enabled by default, to use validbr in `no_std`
+//! environments disable default features:
+//!
+//! ```toml
+//! [dependencies]
+//! validbr = { version = "0.2", default-features = false }
+//! ```
+//!
+//! Types that own strings, such as `Rg` and `EmitterOrg`, require the `alloc` feature
+//! (implied by `std`).
+//!
+//! ## Offline CEP database
+//!
+//! The `cep-db` feature embeds a CEP database which resolves a [`Cep`] to its address through
+//! `Cep::lookup`, see the `cep::db` module. Only a small sample table is bundled, the complete
+//! database is embedded by pointing the `VALIDBR_CEP_DB_SOURCE` environment variable to a table
+//! exported from the Correios DNE at build time. With the sample, most existing Ceps are not
+//! covered, which is reported as `cep::db::CepLookupError::NotCovered`:
+//!
+//! ```toml
+//! [dependencies]
+//! validbr = { version = "0.2", features = ["cep-db"] }
+//! ```
+//!
 //! ## Enable all
 //!
 //! You could enable all features using `complete` flag:
@@ -95,39 +293,59 @@
 //! [dependencies]
 //! validbr = { version = "0.2", features = ["complete"] }
 //! ```
-#![feature(doc_cfg)]
-#![feature(const_evaluatable_checked, const_generics, const_panic)]
-#![allow(incomplete_features)]
-
-#[macro_use]
-extern crate lazy_static;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
-use regex::Regex;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[macro_use] pub(crate) mod macros;
 
 /// Array append utilities.
 pub mod append;
+/// Cep utility functions
+pub mod cep;
+/// Cnh utility functions
+pub mod cnh;
+/// Cns utility functions
+pub mod cns;
 /// Cnpj utility functions
 pub mod cnpj;
 /// Cpf utility functions
 pub mod cpf;
+/// Error messages and localization
+pub mod error;
+/// Inscrição Estadual utility functions
+pub mod inscricao_estadual;
+/// Municipio utility functions
+pub mod municipio;
+/// Parse options for Cpf and Cnpj
+pub mod parse;
+/// Pis utility functions
+pub mod pis;
+/// Placa utility functions
+pub mod placa;
+/// Renavam utility functions
+pub mod renavam;
+/// Serde representation of documents
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_format;
+/// Título de Eleitor utility functions
+pub mod titulo_eleitor;
+/// UF utility functions
+pub mod uf;
 /// RG utility functions
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub mod rg;
 
-#[cfg(feature = "serde")]
+#[cfg(feature = "alloc")]
 use {
-    serde::Serialize,
-    serde::Deserialize
+    crate::rg::Conselho,
+    alloc::string::String
 };
-use crate::EmitterOrg::SSP;
-use std::fmt::Formatter;
-
-
-lazy_static! {
-    pub(crate) static ref NOT_NUMBERS: Regex = Regex::new(r"[^0-9]+").unwrap();
-    pub(crate) static ref ONLY_NUMBERS: Regex = Regex::new(r"^[0-9]+$").unwrap();
-}
+use core::fmt::Formatter;
 
 
 /// CPF consists of nine digits and two verifier digits.
@@ -167,7 +385,6 @@ lazy_static! {
 ///
 /// These numbers could be obtained through `[calculate_verifier_digits]`.
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Cpf {
     /// First 9 digits of CPF.
     pub digits: [u8; 9],
@@ -178,6 +395,9 @@ pub struct Cpf {
 /// CNPJ consists of eight based digits, four digits for the branch (the number of the registered
 /// company) and two verifier digits.
 ///
+/// In the alphanumeric CNPJ format, base and branch digits may also be letters, which are represented
+/// by their ASCII code minus 48 (`A` is `17`, `Z` is `42`) and use the same algorithm below.
+///
 /// The algorithm to calculate the first verifier digit is:
 ///
 /// ```
@@ -211,24 +431,138 @@ pub struct Cpf {
 ///
 /// These numbers could be obtained through `[calculate_verifier_digits]`.
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Cnpj {
-    /// First 8 digits of Cnpj.
+    /// First 8 digits of Cnpj (`17..=42` for letters of alphanumeric Cnpj).
     pub digits: [u8; 8],
-    /// Four digits of branch.
+    /// Four digits of branch (`17..=42` for letters of alphanumeric Cnpj).
     pub branch_digits: [u8; 4],
     /// Last 2 digits of CPF (the verifier digits).
     pub verifier_digits: [u8; 2],
 }
 
+/// CNH (Carteira Nacional de Habilitação) registry number consists of nine digits and two
+/// verifier digits.
+///
+/// DENATRAN has used two algorithms to calculate the verifier digits, both are accepted, see
+/// [`cnh::calculate_verifier_digits`] and [`cnh::calculate_legacy_verifier_digits`].
+///
+/// See [`Cnh::parse_str`] for examples of Cnh construction, and [`cnh::CnhDetails`] for the other
+/// information of the driver's license.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Cnh {
+    /// First 9 digits of CNH.
+    pub digits: [u8; 9],
+    /// Last 2 digits of CNH (the verifier digits).
+    pub verifier_digits: [u8; 2],
+}
+
+/// RENAVAM (Registro Nacional de Veículos Automotores) consists of ten digits and one verifier
+/// digit, legacy RENAVAM have 9 digits and are padded with two leading zeros.
+///
+/// See [`Renavam::parse_str`] for examples of Renavam construction.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Renavam {
+    /// First 10 digits of RENAVAM.
+    pub digits: [u8; 10],
+    /// Last digit of RENAVAM (the verifier digit).
+    pub verifier_digit: u8,
+}
+
+/// Placa (vehicle license plate) consists of three letters followed by four digits in the old
+/// format, and of a letter in the place of the second digit in the Mercosul format, see
+/// [`placa::PlacaFormat`].
+///
+/// See [`Placa::parse_str`] for examples of Placa construction.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Placa {
+    /// The 7 characters of Placa, as ASCII uppercase letters and digits.
+    pub characters: [u8; 7],
+}
+
+/// PIS (Programa de Integração Social) consists of ten digits and one verifier digit, the same
+/// number is used by PASEP, NIT and NIS.
+///
+/// See [`Pis::parse_str`] for examples of Pis construction.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Pis {
+    /// First 10 digits of PIS.
+    pub digits: [u8; 10],
+    /// Last digit of PIS (the verifier digit).
+    pub verifier_digit: u8,
+}
+
+/// CNS (Cartão Nacional de Saúde) consists of fifteen digits, validated according to its
+/// [`cns::CnsKind`], which is identified by the first digit.
+///
+/// See [`Cns::parse_str`] for examples of Cns construction.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Cns {
+    /// The 15 digits of CNS.
+    pub digits: [u8; 15],
+}
+
+/// Título de Eleitor (voter registration number) consists of eight sequential digits, two digits
+/// identifying the UF where the voter is registered and two verifier digits.
+///
+/// See [`TituloEleitor::parse_str`] for examples of TituloEleitor construction.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct TituloEleitor {
+    /// First 8 digits of Título de Eleitor (the sequential number).
+    pub digits: [u8; 8],
+    /// Digits 9 and 10 of Título de Eleitor, which identify the UF, see [`titulo_eleitor::Domicilio`].
+    pub domicilio_digits: [u8; 2],
+    /// Last 2 digits of Título de Eleitor (the verifier digits).
+    pub verifier_digits: [u8; 2],
+}
+
+/// Inscrição Estadual (state taxpayer registration) of a contributor of ICMS, which length, format
+/// and verifier digits depend on the UF which issued it.
+///
+/// See [`InscricaoEstadual::parse_str`] for examples of InscricaoEstadual construction.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum InscricaoEstadual {
+    /// Inscrição Estadual issued by an UF, see [`inscricao_estadual::Inscricao`].
+    Inscrita(inscricao_estadual::Inscricao),
+    /// Contributor exempt from Inscrição Estadual, written as `ISENTO`.
+    Isento,
+}
+
+/// CEP (Código de Endereçamento Postal) consists of eight digits, the first five identify the
+/// region, sub-region, sector, sub-sector and sector divisor, and the last three are the suffix
+/// which identify the addresses in the sector divisor.
+///
+/// See [`Cep::parse_str`] for examples of Cep construction.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Cep {
+    /// The 8 digits of CEP.
+    pub digits: [u8; 8],
+}
+
+/// Brazilian municipality, identified by the 7 digits code assigned by IBGE.
+///
+/// Municipios are resolved from the bundled table, see [`Municipio::from_code`] and
+/// [`Municipio::find`].
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Municipio {
+    /// IBGE code of the Municipio, including the verifier digit.
+    pub code: u32,
+    /// Name of the Municipio.
+    pub name: &'static str,
+    /// UF the Municipio belongs to.
+    pub uf: UF,
+    /// Whether the Municipio is the capital of its UF.
+    pub capital: bool,
+}
+
 /// RG does not have a standard for its format,
 /// so validbr uses [`String`] representing the RG code.
 /// Some emitters uses modulo 11 validation, others don't, some of them uses number only
 /// with verifier digit, others don't and includes Letters and special chars.
 ///
 /// See [`Rg::new`] for examples of Rg construction.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Rg {
     /// RG code/Number.
     pub code: String,
@@ -238,37 +572,88 @@ pub struct Rg {
 
 
 /// List of governmental organizations which emits Brazilian Registries.
+///
+/// Organizations which are present in every UF hold the UF of the emitter, see the `rg` module
+/// for their abbreviations and full names.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EmitterOrg {
+    /// Secretaria de Segurança Pública.
     SSP(UF),
-    PoliciaFedaral,
+    /// Secretaria de Estado da Segurança Pública.
+    SESP(UF),
+    /// Secretaria de Defesa Social.
+    SDS(UF),
+    /// Secretaria de Estado de Justiça e Segurança Pública.
+    SEJUSP(UF),
+    /// Polícia Civil.
+    PC(UF),
+    /// Instituto de Identificação da Polícia Civil.
+    IIPC(UF),
+    /// Departamento Estadual de Trânsito.
+    DETRAN(UF),
+    /// Instituto Félix Pacheco, which emits the Rgs of Rio de Janeiro.
+    IFP,
+    /// Polícia Federal.
+    PoliciaFederal,
+    /// Cartório de Registro Civil.
     CartorioCivil,
+    /// Exército Brasileiro.
+    Exercito,
+    /// Marinha do Brasil.
+    Marinha,
+    /// Comando da Aeronáutica.
+    Aeronautica,
+    /// Professional council of the UF, such as OAB and CRM.
+    Conselho(Conselho, UF),
+    /// Divisão de Polícia Marítima, Aeroportuária e de Fronteiras, which emitted the RNE of
+    /// foreign residents.
+    DPMAF,
+    /// Coordenação-Geral de Polícia de Imigração, which emits the CRNM of foreign residents.
+    CGPI,
+    /// Any other organization.
     Other(String)
 }
 
-impl std::fmt::Display for EmitterOrg {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        return match self {
-            SSP(uf) => {
-                write!(f, "SSP{}", uf)
-            },
-            EmitterOrg::PoliciaFedaral => {
-                write!(f, "Polícia Federal")
-            },
-            EmitterOrg::CartorioCivil => {
-                write!(f, "Cartório Civil")
-            }
-            EmitterOrg::Other(other) => {
-                write!(f, "{}", other)
-            }
+/// Formats the abbreviation of the organization followed by its UF, for example: `SSPSP`, or
+/// the full name followed by the UF with the alternate flag (`{:#}`), for example:
+/// `Secretaria de Segurança Pública - SP`.
+///
+/// The Polícia Federal and the Cartório Civil keep their previous formatting, `Polícia Federal`
+/// and `Cartório Civil`, their abbreviations are provided by [`EmitterOrg::abbreviation`].
+///
+/// # Example
+///
+/// ```
+/// use validbr::EmitterOrg;
+/// use validbr::UF::SP;
+///
+/// assert_eq!(format!("{}", EmitterOrg::DETRAN(SP)), "DETRANSP");
+/// assert_eq!(format!("{:#}", EmitterOrg::DETRAN(SP)), "Departamento Estadual de Trânsito - SP");
+/// assert_eq!(format!("{}", EmitterOrg::PoliciaFederal), "Polícia Federal");
+/// assert_eq!(format!("{}", EmitterOrg::CartorioCivil), "Cartório Civil");
+/// assert_eq!(EmitterOrg::PoliciaFederal.abbreviation(), "DPF");
+/// ```
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for EmitterOrg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (self, self.uf()) {
+            (EmitterOrg::Other(other), _) => f.write_str(other),
+            (EmitterOrg::PoliciaFederal, _) if !f.alternate() => f.write_str("Polícia Federal"),
+            (EmitterOrg::CartorioCivil, _) if !f.alternate() => f.write_str("Cartório Civil"),
+            (_, Some(uf)) if f.alternate() => write!(f, "{} - {}", self.name(), uf),
+            (_, Some(uf)) => write!(f, "{}{}", self.abbreviation(), uf),
+            (_, None) if f.alternate() => f.write_str(self.name()),
+            (_, None) => f.write_str(self.abbreviation()),
         }
     }
 }
 
 /// List of Brazilian Federative Units (Unidades Federativas).
-#[derive(Debug, Eq, PartialEq, Hash, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+///
+/// See the `uf` module for names, IBGE codes, macro-regions and capitals of the UFs.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
 pub enum UF {
     AC,
     AL,
@@ -299,18 +684,16 @@ pub enum UF {
     TO
 }
 
-impl std::fmt::Display for UF {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+impl core::fmt::Display for UF {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.abbreviation())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::EmitterOrg::SSP;
-    use crate::UF::SP;
 
-    #[cfg(feature = "rand")]
+    #[cfg(all(feature = "rand", feature = "std"))]
     #[test]
     fn random_cpf() {
         use rand::Rng;
@@ -323,7 +706,7 @@ mod tests {
         assert_eq!(verifier.1, cpf.verifier_digits[1]);
     }
 
-    #[cfg(feature = "rand")]
+    #[cfg(all(feature = "rand", feature = "std"))]
     #[test]
     fn random_cnpj() {
         use rand::Rng;
@@ -336,7 +719,7 @@ mod tests {
         assert_eq!(verifier.1, cnpj.verifier_digits[1]);
     }
 
-    #[cfg(feature = "rand")]
+    #[cfg(all(feature = "rand", feature = "std"))]
     #[test]
     fn random_cnpj_with_specific_branch() {
         use rand::Rng;
@@ -344,7 +727,7 @@ mod tests {
         use crate::cnpj::Branch;
 
         let mut rng = rand::thread_rng();
-        let branch = Branch::from_u8(0012).unwrap();
+        let branch = Branch::from_u8(12).unwrap();
         let cnpj: Cnpj = rng.sample(branch);
 
         assert_eq!([0, 0, 1, 2], cnpj.branch_digits);
@@ -354,12 +737,1015 @@ mod tests {
         assert_eq!(verifier.1, cnpj.verifier_digits[1]);
     }
 
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn random_alphanumeric_cnpj() {
+        use rand::Rng;
+        use crate::Cnpj;
+        use crate::cnpj::AlphanumericCnpj;
+
+        let mut rng = rand::thread_rng();
+        let cnpj: Cnpj = rng.sample(AlphanumericCnpj);
+
+        assert_eq!(Cnpj::parse_str(&format!("{}", cnpj)), Ok(cnpj));
+    }
+
+    #[test]
+    fn alphanumeric_cnpj() {
+        use crate::Cnpj;
+        use crate::cnpj::CnpjCreationError;
+        use crate::error::{FormatMismatch, VerifierDigitsMismatch};
+
+        let cnpj = Cnpj::parse_str("12ABC34501DE35").expect("Valid alphanumeric cnpj");
+        assert_eq!(format!("{}", cnpj), "12.ABC.345/01DE-35");
+        assert_eq!(Cnpj::parse_str("12.ABC.345/01DE-35"), Ok(cnpj));
+
+        assert_eq!(Cnpj::parse_str("12.ABC.345/01DE-36"), Err(CnpjCreationError::InvalidCnpjDigits(VerifierDigitsMismatch { expected: [3, 5], found: [3, 6] })));
+        assert_eq!(Cnpj::parse_str("12.ABC.345/01DE-3X"), Err(CnpjCreationError::InvalidCnpjStringFormat(FormatMismatch { position: 17, found: Some('X'), input_length: 18 })));
+        assert_eq!(Cnpj::new([1, 2, 10, 18, 19, 3, 4, 5], [0, 1, 20, 21], [3, 5]), Err(CnpjCreationError::DigitsOutOfBounds));
+    }
+
+    #[test]
+    fn const_parsing() {
+        use crate::{Cnpj, Cpf};
+
+        assert_eq!(cpf!("887.614.320-32"), Cpf::parse_str("887.614.320-32").unwrap());
+        assert_eq!(cpf!("00000000191"), Cpf::parse_str("000.000.001-91").unwrap());
+        assert_eq!(cnpj!("80.906.404/0001-88"), Cnpj::parse_str("80906404000188").unwrap());
+        assert_eq!(cnpj!("12ABC34501DE35"), Cnpj::parse_str("12.ABC.345/01DE-35").unwrap());
+
+        for invalid in ["123.456.789-10", "111.111.111-11", "123.456.789/09", "1234567890", "123456789099"] {
+            assert!(std::panic::catch_unwind(|| Cpf::parse_const(invalid)).is_err(), "{}", invalid);
+            assert!(Cpf::parse_str(invalid).is_err(), "{}", invalid);
+        }
+
+        for invalid in ["12.345.678/0001-96", "12.abc.345/01de-35", "12.345.678.0001-95", "1234567800019", "12.ABC.345/01DE-3A"] {
+            assert!(std::panic::catch_unwind(|| Cnpj::parse_const(invalid)).is_err(), "{}", invalid);
+            assert!(Cnpj::parse_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn fiscal_region() {
+        use crate::Cpf;
+        use crate::cpf::FiscalRegion;
+        use crate::UF;
+
+        for digit in 0..=9 {
+            let region = FiscalRegion::from_digit(digit).unwrap();
+            for uf in region.ufs() {
+                assert_eq!(FiscalRegion::of(uf), region);
+            }
+        }
+        assert_eq!(FiscalRegion::from_digit(10), None);
+
+        let cpf = Cpf::parse_str("887.614.320-32").unwrap();
+        assert_eq!(cpf.fiscal_region(), FiscalRegion::of(&UF::RS));
+        assert_eq!(cpf.fiscal_region().to_string(), "10ª Região Fiscal");
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn random_cpf_of_fiscal_region() {
+        use rand::Rng;
+        use crate::Cpf;
+        use crate::cpf::FiscalRegion;
+        use crate::UF;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let cpf: Cpf = rng.sample(UF::BA);
+            assert_eq!(cpf.fiscal_region(), FiscalRegion::of(&UF::SE));
+            assert_eq!(Cpf::new(cpf.digits, cpf.verifier_digits), Ok(cpf));
+        }
+    }
+
+    #[test]
+    fn cnh() {
+        use crate::Cnh;
+        use crate::cnh::{calculate_legacy_verifier_digits, calculate_verifier_digits, CnhAlgorithm, CnhCategory, CnhCreationError, Date};
+        use crate::error::VerifierDigitsMismatch;
+
+        let cnh = Cnh::parse_str("02978515070").unwrap();
+        assert_eq!(cnh.algorithm(), CnhAlgorithm::Current);
+        assert_eq!(cnh.to_string(), "02978515070");
+
+        // Legacy algorithm differs from the current one when the dsc adjustment is applied.
+        assert_eq!(calculate_verifier_digits([0, 0, 4, 4, 3, 1, 4, 7, 1]), (0, 8));
+        assert_eq!(calculate_legacy_verifier_digits([0, 0, 4, 4, 3, 1, 4, 7, 1]), Some((0, 0)));
+        assert_eq!(Cnh::parse_str("00443147108").map(|cnh| cnh.algorithm()), Ok(CnhAlgorithm::Current));
+        assert_eq!(Cnh::parse_str("00443147100").map(|cnh| cnh.algorithm()), Ok(CnhAlgorithm::Legacy));
+        assert_eq!(Cnh::parse_str("00443147109"), Err(CnhCreationError::InvalidCnhDigits(VerifierDigitsMismatch { expected: [0, 8], found: [0, 9] })));
+
+        assert_eq!(Cnh::parse_str("11111111111"), Err(CnhCreationError::RepeatedDigits));
+        assert!(matches!(Cnh::parse_str("029.785.150-70"), Err(CnhCreationError::InvalidCnhStringFormat(_))));
+        assert!(matches!(Cnh::parse_str("0297851507"), Err(CnhCreationError::ShortCnhString(_))));
+
+        for valid in ["A", "b", "AB", "ae", "E"] {
+            assert!(valid.parse::<CnhCategory>().is_ok(), "{}", valid);
+        }
+        for invalid in ["", "AA", "BC", "ABC", "F", "BA"] {
+            assert!(invalid.parse::<CnhCategory>().is_err(), "{}", invalid);
+        }
+        assert!(CnhCategory::E.includes(CnhCategory::B));
+        assert!(!CnhCategory::E.includes(CnhCategory::A));
+        assert!(!CnhCategory::A.includes(CnhCategory::B));
+        assert!(CnhCategory::AB.includes(CnhCategory::AB));
+
+        assert!(Date::new(2000, 2, 29).is_ok());
+        assert!(Date::new(1900, 2, 29).is_err());
+        assert!(Date::new(2024, 13, 1).is_err());
+        assert!(Date::new(2024, 4, 31).is_err());
+        assert!(Date::parse_str("2024/02/10").is_err());
+        assert!(Date::parse_str("1/2/2024").is_err());
+        assert!(Date::parse_str("01/02-2024").is_err());
+        assert!(Date::new(2020, 12, 31).unwrap() < Date::new(2021, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn cep() {
+        use crate::{Cep, UF};
+        use crate::cep::CepCreationError;
+
+        let capitals = [
+            ("01310-100", UF::SP), ("20040-020", UF::RJ), ("29010-000", UF::ES), ("30130-000", UF::MG),
+            ("40020-000", UF::BA), ("49010-000", UF::SE), ("50030-000", UF::PE), ("57020-000", UF::AL),
+            ("58010-000", UF::PB), ("59010-000", UF::RN), ("60060-000", UF::CE), ("64000-000", UF::PI),
+            ("65010-000", UF::MA), ("66010-000", UF::PA), ("68900-000", UF::AP), ("69005-000", UF::AM),
+            ("69301-000", UF::RR), ("69900-000", UF::AC), ("70040-010", UF::DF), ("74000-000", UF::GO),
+            ("76801-000", UF::RO), ("77001-000", UF::TO), ("78005-000", UF::MT), ("79002-000", UF::MS),
+            ("80010-000", UF::PR), ("88010-000", UF::SC), ("90010-000", UF::RS),
+        ];
+
+        for (cep, uf) in capitals.iter() {
+            assert_eq!(Cep::parse_str(cep).unwrap().uf().as_ref(), Some(uf), "{}", cep);
+        }
+
+        let cep = Cep::parse_str("73700-000").unwrap();
+        assert_eq!(cep.uf(), Some(UF::GO));
+        assert_eq!((cep.region(), cep.sub_region(), cep.sector(), cep.sub_sector(), cep.sector_divisor()), (7, 3, 7, 0, 0));
+        assert_eq!(cep.suffix(), [0, 0, 0]);
+        assert_eq!(Cep::parse_str("73699-999").unwrap().uf(), Some(UF::DF));
+        assert_eq!(Cep::parse_str("78900-000").unwrap().uf(), None);
+
+        assert!(matches!(Cep::parse_str("01310.100"), Err(CepCreationError::InvalidCepStringFormat(_))));
+        assert!(matches!(Cep::parse_str("0131010"), Err(CepCreationError::ShortCepString(_))));
+        assert_eq!(u32::from(Cep::parse_str("01310-100").unwrap()), 1_310_100);
+    }
+
+    #[cfg(feature = "cep-db")]
+    #[test]
+    fn cep_db() {
+        use crate::{Cep, UF};
+        use crate::cep::db::{is_sample, search_prefix, CepLookupError};
+        use crate::cep::CepCreationError;
+        use core::convert::TryFrom;
+
+        // Only properties which hold for any table in VALIDBR_CEP_DB_SOURCE are asserted.
+        assert_eq!(is_sample(), option_env!("VALIDBR_CEP_DB_SOURCE").is_none());
+        let missing = || if is_sample() { CepLookupError::NotCovered } else { CepLookupError::NotFound };
+        let addresses: Vec<_> = search_prefix("").unwrap().collect();
+        assert!(!addresses.is_empty());
+        assert!(addresses.windows(2).all(|w| u32::from(&w[0].cep) < u32::from(&w[1].cep)));
+        for address in addresses.iter() {
+            assert_eq!(address.cep.lookup().as_ref(), Ok(address), "{}", address);
+        }
+
+        for pair in addresses.windows(2).take(1000) {
+            let next = u32::from(&pair[0].cep) + 1;
+            if next < u32::from(&pair[1].cep) {
+                assert_eq!(Cep::try_from(next).unwrap().lookup(), Err(missing()));
+            }
+        }
+        assert_eq!(Cep::parse_str("00000-001").unwrap().lookup(), Err(missing()));
+        assert_eq!(Cep::parse_str("01001-000").unwrap().lookup().map(|a| (a.municipio, a.uf)), Ok(("São Paulo", UF::SP)));
+
+        for prefix in ["0", "01310", "01310-1", "7015090", "70150900", "8"].iter() {
+            let digits = prefix.replace('-', "");
+            let expected: Vec<_> = addresses.iter().filter(|a| a.cep.to_string().replace('-', "").starts_with(&digits)).cloned().collect();
+            match search_prefix(prefix) {
+                Ok(search) => assert_eq!(search.collect::<Vec<_>>(), expected, "{}", prefix),
+                Err(error) => {
+                    assert!(is_sample() && expected.is_empty(), "{}", prefix);
+                    assert_eq!(error, CepLookupError::NotCovered);
+                }
+            }
+        }
+        match search_prefix("00000") {
+            Ok(search) => assert_eq!(search.count(), 0),
+            Err(error) => assert_eq!(error, missing()),
+        }
+        assert_eq!(CepLookupError::NotCovered.to_string(), "CEP not covered by the sample CEP database");
+
+        let invalid = |error| matches!(error, Err(CepLookupError::InvalidPrefix(CepCreationError::InvalidCepStringFormat(_))));
+        assert!(invalid(search_prefix("123456789")));
+        assert!(search_prefix("01310-").is_ok());
+        assert!(invalid(search_prefix("01310--")));
+        assert!(invalid(search_prefix("0131-0")));
+    }
+
+    #[test]
+    fn municipio() {
+        use crate::{Municipio, UF};
+        use crate::municipio::{validate_code, MunicipioCreationError, VERIFIER_DIGIT_EXCEPTIONS};
+
+        for municipio in Municipio::all() {
+            assert_eq!(validate_code(municipio.code), Ok(municipio.uf), "{}", municipio);
+            assert_eq!(Municipio::from_code(municipio.code), Ok(municipio));
+            assert_eq!(Municipio::find(&municipio.uf, municipio.name), Some(municipio));
+        }
+        assert!(Municipio::all().windows(2).all(|w| w[0].code < w[1].code));
+
+        for uf in UF::ALL.iter() {
+            assert_eq!(Municipio::of(uf).filter(|municipio| municipio.capital).count(), 1, "{}", uf);
+        }
+
+        for code in VERIFIER_DIGIT_EXCEPTIONS.iter() {
+            assert!(validate_code(*code).is_ok());
+            assert_eq!(Municipio::from_code(*code).map(|municipio| municipio.code), Ok(*code));
+        }
+
+        assert_eq!(Municipio::find(&UF::SP, "  SÃO JOSÉ DOS CAMPOS "), Municipio::from_code(3549904).ok());
+        assert_eq!(Municipio::find(&UF::GO, "abadia de goias").map(|municipio| municipio.code), Some(5200050));
+        assert_eq!(Municipio::from_code(2201919).unwrap().name, "Bom Princípio do Piauí");
+        assert_eq!(Municipio::from_code(10_000_000), Err(MunicipioCreationError::CodeOutOfRange));
+        assert_eq!(
+            Municipio::from_code(3550307).unwrap_err().to_string(),
+            "invalid IBGE municipality code: verifier digit should be 8, found 7"
+        );
+    }
+
+    #[test]
+    fn uf() {
+        use crate::UF;
+        use crate::uf::{InvalidUf, InvalidUfCode, Regiao};
+        use std::convert::TryFrom;
+
+        for uf in UF::ALL.iter().copied() {
+            assert_eq!(uf.abbreviation().parse(), Ok(uf));
+            assert_eq!(uf.abbreviation().to_lowercase().parse(), Ok(uf));
+            assert_eq!(uf.name().parse(), Ok(uf));
+            assert_eq!(uf.name().to_uppercase().parse(), Ok(uf));
+            assert_eq!(UF::try_from(uf.ibge_code()), Ok(uf));
+            assert_eq!(uf.capital().uf, uf);
+            assert!(uf.regiao().ufs().any(|other| other == uf));
+        }
+
+        assert_eq!(Regiao::ALL.iter().map(|regiao| regiao.ufs().count()).sum::<usize>(), UF::ALL.len());
+        assert_eq!(Regiao::CentroOeste.ufs().collect::<Vec<_>>(), vec![UF::DF, UF::GO, UF::MT, UF::MS]);
+        assert_eq!(Regiao::CentroOeste.to_string(), "Centro-Oeste");
+
+        assert_eq!("Espirito Santo".parse(), Ok(UF::ES));
+        assert_eq!(" parana ".parse(), Ok(UF::PR));
+        assert_eq!("Mato Grosso".parse(), Ok(UF::MT));
+        assert_eq!("Paulo".parse::<UF>(), Err(InvalidUf));
+        assert_eq!(UF::try_from(34), Err(InvalidUfCode(34)));
+        assert_eq!(u8::from(UF::DF), 53);
+        assert_eq!(UF::RS.to_string(), "RS");
+        assert!(UF::AC < UF::TO);
+    }
+
+    #[test]
+    fn pis() {
+        use crate::Pis;
+        use crate::pis::{calculate_verifier_digit, PisCreationError};
+        use crate::parse::ParseOptions;
+        use crate::error::VerifierDigitsMismatch;
+        use core::convert::TryFrom;
+
+        assert_eq!(calculate_verifier_digit([1, 7, 0, 1, 2, 3, 4, 5, 6, 7]), 3);
+        assert_eq!(calculate_verifier_digit([1, 2, 3, 4, 5, 6, 7, 8, 9, 0]), 0);
+
+        let pis = Pis::parse_str("170.12345.67-3").unwrap();
+        assert_eq!(pis.to_string(), "170.12345.67-3");
+        assert_eq!(Pis::parse_str("17012345673"), Ok(pis.clone()));
+        assert_eq!(u64::from(&pis), 17012345673);
+        assert_eq!(Pis::try_from(17012345673u64), Ok(pis));
+        assert_eq!(Pis::try_from(1234567897u64).unwrap().to_string(), "012.34567.89-7");
+
+        assert_eq!(
+            Pis::parse_str("170.12345.67-4"),
+            Err(PisCreationError::InvalidPisDigits(VerifierDigitsMismatch { expected: [3], found: [4] }))
+        );
+        assert_eq!(Pis::parse_str("000.00000.00-0"), Err(PisCreationError::RepeatedDigits));
+        assert!(Pis::new_lenient([0; 10], 0).unwrap().has_repeated_digits());
+        let options = ParseOptions { allow_repeated_digits: true, ..ParseOptions::strict() };
+        assert!(Pis::parse_str_with("00000000000", &options).is_ok());
+        assert_eq!(Pis::new([1, 2, 0, 1, 2, 3, 4, 5, 6, 10], 2), Err(PisCreationError::DigitsOutOfBounds));
+        assert!(matches!(Pis::parse_str("170-12345-67-3"), Err(PisCreationError::InvalidPisStringFormat(_))));
+        assert!(matches!(Pis::parse_str("1701234567"), Err(PisCreationError::ShortPisString(_))));
+        assert_eq!(
+            Pis::parse_str("170.12345.67-4").unwrap_err().to_string(),
+            "invalid PIS: verifier digit should be 3, found 4"
+        );
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn random_pis() {
+        use rand::Rng;
+        use crate::Pis;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let pis: Pis = rng.gen();
+            assert!(!pis.has_repeated_digits());
+            assert_eq!(Pis::parse_str(&pis.to_string()), Ok(pis));
+        }
+    }
+
+    #[test]
+    fn cns() {
+        use crate::{Cns, Pis};
+        use crate::cns::{calculate_definitive_digits, CnsCreationError, CnsKind};
+        use crate::error::VerifierDigitsMismatch;
+
+        assert_eq!(calculate_definitive_digits([1, 7, 0, 1, 2, 3, 4, 5, 6, 7, 3]), [0, 0, 0, 0]);
+        assert_eq!(calculate_definitive_digits([1, 2, 0, 1, 2, 3, 4, 5, 6, 8, 5]), [0, 0, 1, 8]);
+
+        let definitive = Cns::parse_str("170 1234 5673 0000").unwrap();
+        assert_eq!(definitive.kind(), CnsKind::Definitive);
+        assert_eq!(Cns::from_pis(&Pis::parse_str("170.12345.67-3").unwrap()), Ok(definitive));
+
+        let provisional = Cns::parse_str("712345678901236").unwrap();
+        assert_eq!(provisional.kind(), CnsKind::Provisional);
+        assert_eq!(provisional.to_string(), "712 3456 7890 1236");
+        assert_eq!(Cns::parse_str("700 0000 0000 0005").unwrap().kind(), CnsKind::Provisional);
+
+        assert_eq!(
+            Cns::parse_str("120 1234 5672 0008"),
+            Err(CnsCreationError::InvalidCnsDigits(VerifierDigitsMismatch { expected: [0, 0, 0, 9], found: [0, 0, 0, 8] }))
+        );
+        assert_eq!(Cns::parse_str("712 3456 7890 1237"), Err(CnsCreationError::InvalidProvisionalCns));
+        assert_eq!(Cns::parse_str("312 3456 7890 1236"), Err(CnsCreationError::InvalidCnsKind(3)));
+        assert_eq!(Cns::new([1; 15]).unwrap_err().to_string(), "invalid CNS: verifier digit should be 0000, found 1111");
+        assert!(matches!(Cns::parse_str("712-3456-7890-1236"), Err(CnsCreationError::InvalidCnsStringFormat(_))));
+        assert!(matches!(Cns::parse_str("71234567890123"), Err(CnsCreationError::ShortCnsString(_))));
+        assert_eq!(
+            Cns::parse_str("512 3456 7890 1236").unwrap_err().to_string(),
+            "invalid CNS: first digit must be 1, 2, 7, 8 or 9, found 5"
+        );
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn random_cns() {
+        use rand::Rng;
+        use crate::Cns;
+        use crate::cns::CnsKind;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let cns: Cns = rng.gen();
+            assert_eq!(Cns::parse_str(&cns.to_string()), Ok(cns));
+
+            for kind in [CnsKind::Definitive, CnsKind::Provisional].iter() {
+                let cns: Cns = rng.sample(kind);
+                assert_eq!(cns.kind(), *kind);
+                assert_eq!(Cns::new(cns.digits), Ok(cns));
+            }
+        }
+    }
+
+    #[test]
+    fn renavam() {
+        use crate::Renavam;
+        use crate::renavam::{calculate_verifier_digit, RenavamCreationError};
+        use crate::error::VerifierDigitsMismatch;
+        use core::convert::TryFrom;
+
+        assert_eq!(calculate_verifier_digit([9, 8, 7, 6, 5, 4, 3, 2, 1, 0]), 3);
+        assert_eq!(calculate_verifier_digit([0, 0, 0, 0, 0, 0, 0, 0, 0, 1]), 9);
+
+        let renavam = Renavam::parse_str("98765432103").unwrap();
+        assert!(!renavam.is_legacy());
+        assert_eq!(renavam.to_string(), "98765432103");
+        assert_eq!(u64::from(&renavam), 98765432103);
+        assert_eq!(Renavam::try_from(98765432103u64), Ok(renavam));
+
+        let legacy = Renavam::parse_str("000000019").unwrap();
+        assert!(legacy.is_legacy());
+        assert_eq!(legacy.to_string(), "00000000019");
+        assert_eq!(Renavam::try_from(19u64), Ok(legacy));
+
+        assert_eq!(
+            Renavam::parse_str("98765432104"),
+            Err(RenavamCreationError::InvalidRenavamDigits(VerifierDigitsMismatch { expected: [3], found: [4] }))
+        );
+        assert_eq!(Renavam::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 10], 9), Err(RenavamCreationError::DigitsOutOfBounds));
+        assert!(matches!(Renavam::parse_str("9876543210-3"), Err(RenavamCreationError::InvalidRenavamStringFormat(_))));
+        assert!(matches!(Renavam::parse_str("0639884962"), Err(RenavamCreationError::ShortRenavamString(_))));
+        assert!(matches!(Renavam::parse_str("39884962"), Err(RenavamCreationError::ShortRenavamString(_))));
+        assert_eq!(
+            Renavam::parse_str("98765432104").unwrap_err().to_string(),
+            "invalid RENAVAM: verifier digit should be 3, found 4"
+        );
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn random_renavam() {
+        use rand::Rng;
+        use crate::Renavam;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let renavam: Renavam = rng.gen();
+            assert_eq!(Renavam::parse_str(&renavam.to_string()), Ok(renavam));
+        }
+    }
+
+    #[test]
+    fn placa() {
+        use crate::{Placa, UF};
+        use crate::placa::{PlacaCreationError, PlacaFormat};
+        use crate::parse::ParseOptions;
+
+        let old = Placa::parse_str("MOX-0987").unwrap();
+        assert_eq!(old.format(), PlacaFormat::Old);
+        assert_eq!(old.uf(), Some(UF::ES));
+        assert_eq!(old.to_old(), Some(old.clone()));
+
+        let mercosul = old.to_mercosul();
+        assert_eq!(mercosul.format(), PlacaFormat::Mercosul);
+        assert_eq!(mercosul.to_string(), "MOX0J87");
+        assert_eq!(mercosul.uf(), None);
+        assert_eq!(mercosul.to_old().and_then(|old| old.uf()), Some(UF::ES));
+        assert_eq!(mercosul.to_mercosul(), mercosul);
+        assert_eq!(mercosul.to_old(), Some(old));
+
+        assert_eq!(Placa::parse_str("AAA0000").unwrap().uf(), Some(UF::PR));
+        assert_eq!(Placa::parse_str("NFB9999").unwrap().uf(), Some(UF::AP));
+        assert_eq!(Placa::parse_str("NFC0000").unwrap().uf(), None);
+        assert_eq!(Placa::parse_str("BRA2E19").unwrap().to_old(), Some(Placa::parse_str("BRA-2419").unwrap()));
+        assert_eq!(Placa::parse_str("BRA2Z19").unwrap().to_old(), None);
+
+        assert!(Placa::parse_str("abc1234").is_err());
+        assert_eq!(Placa::parse_str_with("abc-1234", &ParseOptions::lenient()), Placa::parse_str("ABC1234"));
+        assert_eq!(Placa::new(*b"ABC12D4"), Err(PlacaCreationError::InvalidCharacters));
+        assert!(matches!(Placa::parse_str("AB1-1234"), Err(PlacaCreationError::InvalidPlacaStringFormat(_))));
+        assert!(matches!(Placa::parse_str("ABC1D2E"), Err(PlacaCreationError::InvalidPlacaStringFormat(_))));
+        assert!(matches!(Placa::parse_str("ABC123"), Err(PlacaCreationError::ShortPlacaString(_))));
+        assert_eq!(Placa::parse_str("BRA2E19").unwrap().uf(), None);
+        assert_eq!(Placa::parse_str_with("abc-1c34", &ParseOptions::lenient()), Placa::parse_str("ABC1C34"));
+        assert_eq!(
+            Placa::parse_str("ABC-1C34").unwrap_err().to_string(),
+            "invalid plate format, expected AAA-0000, AAA0000 or AAA0A00: unexpected character '-' at position 3"
+        );
+        assert_eq!(
+            Placa::parse_str("AB1-1234").unwrap_err().to_string(),
+            "invalid plate format, expected AAA-0000, AAA0000 or AAA0A00: unexpected character '1' at position 2"
+        );
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn random_placa() {
+        use rand::Rng;
+        use crate::{Placa, UF};
+        use crate::placa::PlacaFormat;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let placa: Placa = rng.gen();
+            assert_eq!(Placa::parse_str(&placa.to_string()), Ok(placa));
+
+            for format in [PlacaFormat::Old, PlacaFormat::Mercosul].iter() {
+                let placa: Placa = rng.sample(format);
+                assert_eq!(placa.format(), *format);
+            }
+
+            for uf in UF::ALL.iter() {
+                let placa: Placa = rng.sample(uf);
+                assert_eq!(placa.format(), PlacaFormat::Old);
+                assert_eq!(placa.uf(), Some(*uf));
+            }
+        }
+    }
+
+    #[test]
+    fn inscricao_estadual() {
+        use crate::{InscricaoEstadual, UF};
+        use crate::inscricao_estadual::InscricaoEstadualCreationError;
+        use crate::error::{Language, LocalizedError, VerifierDigitsMismatch};
+        use crate::parse::ParseOptions;
+
+        let valid = [
+            (UF::AC, "01.004.823/001-12"),
+            (UF::AL, "240000048"),
+            (UF::AP, "030123459"),
+            (UF::AP, "030170011"),
+            (UF::AM, "04.154.184-7"),
+            (UF::BA, "123456-63"),
+            (UF::BA, "612345-57"),
+            (UF::BA, "1000003-06"),
+            (UF::BA, "0612345-57"),
+            (UF::CE, "06000001-5"),
+            (UF::DF, "07.300001.001-09"),
+            (UF::ES, "082.560.67-6"),
+            (UF::GO, "10.987.654-7"),
+            (UF::GO, "10.103.105-1"),
+            (UF::GO, "11.094.402-0"),
+            (UF::GO, "11.094.402-1"),
+            (UF::MA, "120000385"),
+            (UF::MT, "0013000001-9"),
+            (UF::MS, "283115947"),
+            (UF::MG, "062.307.904/0081"),
+            (UF::PA, "15-999999-5"),
+            (UF::PB, "06000001-5"),
+            (UF::PR, "123.45678-50"),
+            (UF::PE, "0321418-40"),
+            (UF::PI, "012345679"),
+            (UF::RJ, "99.999.99-3"),
+            (UF::RN, "20.040.040-1"),
+            (UF::RN, "20.0.040.040-0"),
+            (UF::RS, "224/3658792"),
+            (UF::RO, "0000000062521-3"),
+            (UF::RR, "24006628-1"),
+            (UF::SC, "251.040.852"),
+            (UF::SP, "110.042.490.114"),
+            (UF::SE, "27123456-3"),
+            (UF::TO, "290227836"),
+            (UF::TO, "29.01.022783-6"),
+        ];
+
+        for (uf, formatted) in valid.iter() {
+            let ie = InscricaoEstadual::parse_str(*uf, formatted).unwrap_or_else(|e| panic!("{} {}: {}", uf, formatted, e));
+            assert_eq!(ie.uf(), Some(*uf));
+            assert_eq!(ie.to_string(), *formatted);
+
+            let digits: String = formatted.chars().filter(char::is_ascii_digit).collect();
+            assert_eq!(InscricaoEstadual::parse_str(*uf, &digits), Ok(ie.clone()));
+
+            // Any change in the last digit invalidates the Inscrição Estadual, except for the GO
+            // number which accepts both 0 and 1.
+            let mut changed: Vec<u8> = digits.bytes().map(|c| c - b'0').collect();
+            let last = changed.len() - 1;
+            changed[last] = (changed[last] + 2) % 10;
+            assert!(InscricaoEstadual::new(*uf, &changed).is_err(), "{} {}", uf, formatted);
+        }
+
+        assert_eq!(
+            InscricaoEstadual::parse_str(UF::SP, "110.042.491.114"),
+            Err(InscricaoEstadualCreationError::InvalidVerifierDigits(VerifierDigitsMismatch { expected: [0, 8], found: [1, 4] }))
+        );
+        assert_eq!(
+            InscricaoEstadual::parse_str(UF::PB, "06000001-6"),
+            Err(InscricaoEstadualCreationError::InvalidVerifierDigit(VerifierDigitsMismatch { expected: [5], found: [6] }))
+        );
+        assert_eq!(
+            InscricaoEstadual::parse_str(UF::AM, "00.000.000-1"),
+            Err(InscricaoEstadualCreationError::InvalidVerifierDigit(VerifierDigitsMismatch { expected: [0], found: [1] }))
+        );
+        assert_eq!(InscricaoEstadual::parse_str(UF::AL, "250000048"), Err(InscricaoEstadualCreationError::InvalidFixedDigits));
+        assert_eq!(InscricaoEstadual::parse_str(UF::TO, "29.04.022783-6"), Err(InscricaoEstadualCreationError::InvalidFixedDigits));
+        assert_eq!(
+            InscricaoEstadual::new(UF::BA, &[1, 2, 3]),
+            Err(InscricaoEstadualCreationError::InvalidInscricaoEstadualLength { expected: &[8, 9], found: 3 })
+        );
+        assert_eq!(InscricaoEstadual::new(UF::RJ, &[9, 9, 9, 9, 9, 9, 9, 10]), Err(InscricaoEstadualCreationError::DigitsOutOfBounds));
+        assert!(matches!(InscricaoEstadual::parse_str(UF::SP, "110.042.490-114"), Err(InscricaoEstadualCreationError::InvalidInscricaoEstadualStringFormat(_))));
+        assert!(matches!(InscricaoEstadual::parse_str(UF::SP, "11004249011"), Err(InscricaoEstadualCreationError::ShortInscricaoEstadualString(_))));
+        assert!(InscricaoEstadual::parse_str(UF::SP, "isento").is_err());
+        assert!(InscricaoEstadual::parse_str_with(UF::SP, "isento", &ParseOptions::lenient()).unwrap().is_isento());
+        assert_eq!(
+            InscricaoEstadual::new(UF::BA, &[1, 2, 3]).unwrap_err().localized(Language::BrazilianPortuguese).to_string(),
+            "a inscrição estadual deve ter 8 ou 9 dígitos, encontrado 3"
+        );
+    }
+
+    #[test]
+    fn titulo_eleitor() {
+        use crate::{TituloEleitor, UF};
+        use crate::titulo_eleitor::{calculate_verifier_digits, Domicilio, TituloEleitorCreationError};
+        use crate::error::VerifierDigitsMismatch;
+
+        // São Paulo and Minas Gerais use 1 instead of 0 when the remainder is 0.
+        assert_eq!(calculate_verifier_digits([1, 2, 3, 4, 5, 6, 7, 7], [0, 1]), (1, 6));
+        assert_eq!(calculate_verifier_digits([1, 2, 3, 4, 5, 6, 7, 7], [0, 3]), (0, 2));
+        assert_eq!(TituloEleitor::parse_str("1234 5677 0116").unwrap().uf(), Some(UF::SP));
+        assert_eq!(TituloEleitor::parse_str("123456770302").unwrap().uf(), Some(UF::RJ));
+
+        let exterior = TituloEleitor::parse_str("0043 5687 2801").unwrap();
+        assert_eq!(exterior.domicilio(), Domicilio::Exterior);
+        assert_eq!(exterior.uf(), None);
+        assert_eq!(exterior.to_string(), "0043 5687 2801");
+
+        for code in 1..=28 {
+            assert_eq!(Domicilio::from_code(code).map(|domicilio| domicilio.code()), Some(code));
+        }
+        for uf in UF::ALL.iter() {
+            assert_eq!(Domicilio::Uf(*uf).uf(), Some(*uf));
+        }
+        assert_eq!(Domicilio::from_code(0), None);
+        assert_eq!(Domicilio::from_code(29), None);
+
+        assert_eq!(
+            TituloEleitor::parse_str("1234 5677 0115"),
+            Err(TituloEleitorCreationError::InvalidTituloEleitorDigits(VerifierDigitsMismatch { expected: [1, 6], found: [1, 5] }))
+        );
+        assert_eq!(TituloEleitor::parse_str("1234 5677 2916"), Err(TituloEleitorCreationError::InvalidDomicilio(29)));
+        assert!(matches!(TituloEleitor::parse_str("1234-5677-0116"), Err(TituloEleitorCreationError::InvalidTituloEleitorStringFormat(_))));
+        assert!(matches!(TituloEleitor::parse_str("12345677011"), Err(TituloEleitorCreationError::ShortTituloEleitorString(_))));
+        assert_eq!(
+            TituloEleitor::parse_str("1234 5677 0000").unwrap_err().to_string(),
+            "invalid título de eleitor: 00 is not the code of an UF"
+        );
+    }
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[test]
+    fn random_titulo_eleitor() {
+        use rand::Rng;
+        use crate::{TituloEleitor, UF};
+        use crate::titulo_eleitor::Domicilio;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let titulo: TituloEleitor = rng.gen();
+            assert_eq!(TituloEleitor::parse_str(&titulo.to_string()), Ok(titulo));
+
+            let titulo: TituloEleitor = rng.sample(UF::SP);
+            assert_eq!(titulo.domicilio(), Domicilio::Uf(UF::SP));
+            assert_eq!(TituloEleitor::new(titulo.digits, titulo.domicilio_digits, titulo.verifier_digits), Ok(titulo));
+        }
+    }
+
+    #[test]
+    fn repeated_digits() {
+        use crate::{Cpf, Cnpj};
+        use crate::cpf::CpfCreationError;
+        use crate::cnpj::CnpjCreationError;
+
+        for d in 0..=9 {
+            assert_eq!(Cpf::new([d; 9], [d; 2]), Err(CpfCreationError::RepeatedDigits));
+            assert_eq!(Cnpj::new([d; 8], [d; 4], [d; 2]), Err(CnpjCreationError::RepeatedDigits));
+        }
+
+        assert_eq!(Cpf::parse_str("111.111.111-11"), Err(CpfCreationError::RepeatedDigits));
+        assert_eq!(Cnpj::parse_str("00.000.000/0000-00"), Err(CnpjCreationError::RepeatedDigits));
+
+        assert!(Cpf::new_lenient([1; 9], [1; 2]).is_ok());
+        assert!(Cnpj::new_lenient([0; 8], [0; 4], [0; 2]).is_ok());
+        assert!(matches!(Cpf::new_lenient([1; 9], [1, 2]), Err(CpfCreationError::InvalidCpfDigits(_))));
+    }
+
+    #[test]
+    fn cpf_conversions() {
+        use crate::Cpf;
+        use std::convert::TryFrom;
+
+        for formatted in &["123.456.789-09", "000.000.001-91", "045.321.876-80", "887.614.320-32"] {
+            let cpf: Cpf = formatted.parse().expect("Valid cpf");
+            assert_eq!(Cpf::try_from(*formatted).as_ref(), Ok(&cpf));
+
+            let number = u64::from(&cpf);
+            let from_number = Cpf::try_from(number).expect("Valid cpf number");
+            assert_eq!(format!("{}", from_number), *formatted);
+            assert_eq!(u64::from(from_number), number);
+        }
+    }
+
+    #[test]
+    fn cnpj_conversions() {
+        use crate::Cnpj;
+        use std::convert::TryFrom;
+
+        for formatted in &["12.345.678/0001-95", "00.360.305/0001-04", "80.906.404/0001-88"] {
+            let cnpj: Cnpj = formatted.parse().expect("Valid cnpj");
+            assert_eq!(Cnpj::try_from(*formatted).as_ref(), Ok(&cnpj));
+
+            let number = u64::try_from(&cnpj).expect("Numeric cnpj");
+            let from_number = Cnpj::try_from(number).expect("Valid cnpj number");
+            assert_eq!(format!("{}", from_number), *formatted);
+            assert_eq!(u64::try_from(from_number), Ok(number));
+        }
+    }
+
+    #[test]
+    fn parse_options() {
+        use crate::{Cpf, Cnpj};
+        use crate::cpf::CpfCreationError;
+        use crate::cnpj::CnpjCreationError;
+        use crate::error::FormatMismatch;
+        use crate::parse::ParseOptions;
+
+        let mismatch = |position, found, input_length| FormatMismatch { position, found, input_length };
+
+        let cpf = Cpf::parse_str("123.456.789-09").expect("Valid cpf");
+        assert_eq!(Cpf::parse_str("x123.456.789-09y"), Err(CpfCreationError::InvalidCpfStringFormat(mismatch(0, Some('x'), 16))));
+        assert_eq!(Cpf::parse_str("123.456.789-09 "), Err(CpfCreationError::InvalidCpfStringFormat(mismatch(14, Some(' '), 15))));
+        assert_eq!(Cpf::parse_str("123.456.789-0"), Err(CpfCreationError::InvalidCpfStringFormat(mismatch(13, None, 13))));
+        assert_eq!(Cpf::parse_str("123456789x9"), Err(CpfCreationError::InvalidCpfStringFormat(mismatch(9, Some('x'), 11))));
+        assert_eq!(Cpf::parse_str("1234567890"), Err(CpfCreationError::ShortCpfString(mismatch(10, None, 10))));
+        assert_eq!(Cpf::parse_str("123456789091"), Err(CpfCreationError::InvalidCpfStringFormat(mismatch(11, Some('1'), 12))));
+        assert_eq!(Cpf::parse_str("123.456.789-09".replace('.', "á").as_str()), Err(CpfCreationError::InvalidCpfStringFormat(mismatch(3, Some('á'), 16))));
+
+        let lenient = ParseOptions::lenient();
+        for noisy in &["123 456 789 09", "123.456.789/09", "  12345678909\n", "123-456-789-09", "123.456.789-09"] {
+            assert_eq!(Cpf::parse_str_with(noisy, &lenient).as_ref(), Ok(&cpf));
+        }
+        assert_eq!(Cpf::parse_str_with("x123.456.789-09y", &lenient), Err(CpfCreationError::InvalidCpfStringFormat(mismatch(0, Some('x'), 16))));
+        assert_eq!(Cpf::parse_str_with("45321876-80", &lenient), Err(CpfCreationError::ShortCpfString(mismatch(11, None, 11))));
+
+        let padded = ParseOptions::padded();
+        assert!(Cpf::parse_str_with("4532187680", &padded).is_ok());
+        assert_eq!(Cpf::parse_str_with("45.321.876-80", &padded), Err(CpfCreationError::InvalidCpfStringFormat(mismatch(2, Some('.'), 13))));
+        assert!(Cpf::parse_str_with("45.321.876-80", &ParseOptions { pad_leading_zeros: true, ..lenient }).is_ok());
+
+        let repeated = ParseOptions { allow_repeated_digits: true, ..ParseOptions::strict() };
+        assert!(Cpf::parse_str_with("000.000.000-00", &repeated).is_ok());
+        assert!(Cnpj::parse_str_with("00000000000000", &repeated).is_ok());
+
+        assert_eq!(Cnpj::parse_str("x12.345.678/0001-95"), Err(CnpjCreationError::InvalidCnpjStringFormat(mismatch(0, Some('x'), 19))));
+        assert_eq!(Cnpj::parse_str("12.abc.345/01de-35"), Err(CnpjCreationError::InvalidCnpjStringFormat(mismatch(3, Some('a'), 18))));
+        assert!(Cnpj::parse_str_with("12 345 678 0001 95", &lenient).is_ok());
+        assert!(Cnpj::parse_str_with("12.abc.345/01de-35", &lenient).is_ok());
+        assert_eq!(Cnpj::parse_str_with("12.abc.345/01de-3x", &lenient), Err(CnpjCreationError::InvalidCnpjStringFormat(mismatch(17, Some('x'), 18))));
+    }
+
+    #[test]
+    fn errors() {
+        use crate::{Cpf, Cnpj};
+        use crate::error::{Language, LocalizedError};
+        use std::error::Error;
+
+        fn parse_both(cpf: &str, cnpj: &str) -> Result<(Cpf, Cnpj), Box<dyn Error>> {
+            Ok((Cpf::parse_str(cpf)?, Cnpj::parse_str(cnpj)?))
+        }
+
+        assert!(parse_both("123.456.789-09", "12.345.678/0001-95").is_ok());
+
+        let error = parse_both("123.456.789-09", "12.345.678/0001-96").unwrap_err();
+        assert_eq!(error.to_string(), "invalid CNPJ: verifier digit should be 95, found 96");
+
+        let error = Cnpj::parse_str("1234").unwrap_err();
+        assert_eq!(error.localized(Language::BrazilianPortuguese).to_string(), "CNPJ possui menos de 14 caracteres: fim inesperado na posição 4");
+        assert_eq!(error.localized(Language::English).to_string(), error.to_string());
+    }
+
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    #[test]
+    fn serde() {
+        use crate::{Cep, Cnh, Cnpj, Cns, Cpf, EmitterOrg, Pis, Placa, Renavam, Rg, TituloEleitor, UF};
+        use crate::cnh::{CnhCategory, CnhDetails, Date};
+        use crate::rg::RgCreationError;
+
+        let cpf = Cpf::parse_str("123.456.789-09").unwrap();
+        assert_eq!(serde_json::to_string(&cpf).unwrap(), r#""123.456.789-09""#);
+        assert_eq!(serde_json::from_str::<Cpf>(r#""12345678909""#).unwrap(), cpf);
+        assert!(serde_json::from_str::<Cpf>(r#""123.456.789-10""#).is_err());
+
+        let cep = Cep::parse_str("01310-100").unwrap();
+        assert_eq!(serde_json::to_string(&cep).unwrap(), r#""01310-100""#);
+        assert_eq!(serde_json::from_str::<Cep>(r#""01310100""#).unwrap(), cep);
+        assert!(serde_json::from_str::<Cpf>(r#"{"digits":[1,2,3,4,5,6,7,8,9],"verifier_digits":[1,0]}"#).is_err());
+
+        let pis = Pis::parse_str("120.12345.67-2").unwrap();
+        assert_eq!(serde_json::to_string(&pis).unwrap(), r#""120.12345.67-2""#);
+        assert_eq!(serde_json::from_str::<Pis>(r#""12012345672""#).unwrap(), pis);
+        assert!(serde_json::from_str::<Pis>(r#""120.12345.67-3""#).is_err());
+
+        let cns = Cns::parse_str("898 0010 0000 9310").unwrap();
+        assert_eq!(serde_json::to_string(&cns).unwrap(), r#""898 0010 0000 9310""#);
+        assert_eq!(serde_json::from_str::<Cns>(r#""898001000009310""#).unwrap(), cns);
+        assert!(serde_json::from_str::<Cns>(r#""898 0010 0000 9311""#).is_err());
+
+        let renavam = Renavam::parse_str("639884962").unwrap();
+        assert_eq!(serde_json::to_string(&renavam).unwrap(), r#""00639884962""#);
+        assert_eq!(serde_json::from_str::<Renavam>(r#""639884962""#).unwrap(), renavam);
+        assert!(serde_json::from_str::<Renavam>(r#""00639884963""#).is_err());
+
+        let placa = Placa::parse_str("ABC1234").unwrap();
+        assert_eq!(serde_json::to_string(&placa).unwrap(), r#""ABC-1234""#);
+        assert_eq!(serde_json::from_str::<Placa>(r#""ABC-1234""#).unwrap(), placa);
+        assert_eq!(serde_json::from_str::<Placa>(r#""ABC1C34""#).unwrap(), placa.to_mercosul());
+        assert!(serde_json::from_str::<Placa>(r#""ABC-12C4""#).is_err());
+
+        let titulo = TituloEleitor::parse_str("1023 8501 0671").unwrap();
+        assert_eq!(serde_json::to_string(&titulo).unwrap(), r#""1023 8501 0671""#);
+        assert_eq!(serde_json::from_str::<TituloEleitor>(r#""102385010671""#).unwrap(), titulo);
+        assert!(serde_json::from_str::<TituloEleitor>(r#""1023 8501 0672""#).is_err());
+
+        let cnpj = Cnpj::parse_str("12.ABC.345/01DE-35").unwrap();
+        assert_eq!(serde_json::to_string(&cnpj).unwrap(), r#""12.ABC.345/01DE-35""#);
+        assert_eq!(serde_json::from_str::<Cnpj>(r#""12ABC34501DE35""#).unwrap(), cnpj);
+        assert!(serde_json::from_str::<Cnpj>(r#""12.ABC.345/01DE-36""#).is_err());
+
+        assert_eq!(serde_json::to_string(&UF::SP).unwrap(), r#""SP""#);
+        assert_eq!(serde_json::from_str::<UF>(r#""SP""#).unwrap(), UF::SP);
+        let error = serde_json::from_str::<UF>(r#""XX""#).unwrap_err();
+        assert_eq!(error.to_string(), format!("{} at line 1 column 4", "XX".parse::<UF>().unwrap_err()));
+
+        let rg = Rg::new("246781312", EmitterOrg::SSP(UF::SP));
+        assert_eq!(serde_json::to_string(&rg).unwrap(), r#""246781312 SSPSP""#);
+        assert_eq!(serde_json::from_str::<Rg>(r#""24.678.131-2 SSP/SP""#).unwrap(), rg);
+        let error = serde_json::from_str::<Rg>(r#""24.678.131-3 SSP/SP""#).unwrap_err();
+        assert!(error.to_string().starts_with("invalid RG: check digit should be 2, found 3"), "{}", error);
+        assert!(serde_json::from_str::<Rg>(r#""A8974B-X SSPSP""#).is_err());
+
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Person {
+            #[serde(with = "crate::serde_format::rg_unvalidated")]
+            rg: Rg,
+        }
+
+        let person = Person { rg: Rg::new("A8974B-X", EmitterOrg::SSP(UF::SP)) };
+        assert_eq!(serde_json::to_string(&person).unwrap(), r#"{"rg":"A8974B-X SSPSP"}"#);
+        assert_eq!(serde_json::from_str::<Person>(r#"{"rg":"A8974B-X SSPSP"}"#).unwrap(), person);
+        let person = Person { rg: Rg::new("12.345.678-9", EmitterOrg::SSP(UF::RJ)) };
+        assert_eq!(serde_json::from_str::<Person>(&serde_json::to_string(&person).unwrap()).unwrap(), person);
+        assert!(serde_json::from_str::<Person>(r#"{"rg":"246781312"}"#).is_err());
+
+        let error = serde_json::from_str::<Rg>(r#""246781312""#).unwrap_err();
+        assert!(error.to_string().starts_with(&RgCreationError::MissingEmitterOrg.to_string()), "{}", error);
+
+        let rg = Rg::new("123456", EmitterOrg::PoliciaFederal);
+        assert_eq!(serde_json::from_str::<Rg>(&serde_json::to_string(&rg).unwrap()).unwrap(), rg);
+        assert!(serde_json::from_str::<Rg>(r#""123456""#).is_err());
+
+        let details = CnhDetails::new(
+            Cnh::parse_str("02978515070").unwrap(),
+            CnhCategory::AB,
+            Date::new(2010, 5, 20).ok(),
+            Date::new(2030, 5, 20).ok(),
+        ).unwrap();
+        let json = r#"{"cnh":"02978515070","category":"AB","first_license":"2010-05-20","expiry":"2030-05-20"}"#;
+        assert_eq!(serde_json::to_string(&details).unwrap(), json);
+        assert_eq!(serde_json::from_str::<CnhDetails>(json).unwrap(), details);
+        assert!(serde_json::from_str::<CnhDetails>(r#"{"cnh":"02978515070","category":"AB","first_license":"20/05/2030","expiry":"20/05/2010"}"#).is_err());
+        assert!(serde_json::from_str::<CnhDetails>(r#"{"cnh":"02978515071","category":"AB","first_license":null,"expiry":null}"#).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn rg() {
         use crate::Rg;
+        use crate::EmitterOrg::SSP;
+        use crate::UF::SP;
 
         let rg = Rg::new("A8974B-X", SSP(SP));
 
         assert_eq!(format!("{}", rg), "A8974B-X SSPSP");
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rg_parsing() {
+        use crate::{EmitterOrg, Rg};
+        use crate::UF::{RJ, SP};
+        use crate::error::FormatMismatch;
+        use crate::rg::RgCreationError;
+
+        for rg in &["24.678.131-2 SSP/SP", "24.678.131-2 SSP-SP", "246781312 SSP SP", " 24678131-2  ssp/sp "] {
+            assert_eq!(Rg::parse_full_str(rg), Ok(Rg::new("246781312", EmitterOrg::SSP(SP))), "{}", rg);
+        }
+
+        let rg = Rg::parse_full_str("12.345.678-9 SSP/RJ").unwrap();
+        assert_eq!(rg.to_string(), "123456789 SSPRJ");
+        assert_eq!(Rg::parse_full_str(&rg.to_string()), Ok(rg));
+
+        for emitter_org in &[EmitterOrg::SSP(RJ), EmitterOrg::PoliciaFederal, EmitterOrg::CartorioCivil, EmitterOrg::Other("SPTC".to_string())] {
+            assert_eq!(emitter_org.to_string().parse::<EmitterOrg>().as_ref(), Ok(emitter_org));
+            let rg = Rg::new("123456789", emitter_org.clone());
+            assert_eq!(Rg::parse_full_str(&rg.to_string()), Ok(rg));
+        }
+
+        assert_eq!("dpf".parse::<EmitterOrg>(), Ok(EmitterOrg::PoliciaFederal));
+        assert_eq!("Cartorio".parse::<EmitterOrg>(), Ok(EmitterOrg::CartorioCivil));
+        assert_eq!(Rg::parse_full_str("MG 12.345.678 SSP MG").map(|rg| rg.code), Ok("MG12345678".to_string()));
+        assert_eq!(
+            Rg::parse_full_str("123456 DETRAN RJ").map(|rg| rg.emitter_org),
+            Ok(EmitterOrg::DETRAN(RJ))
+        );
+        assert_eq!(Rg::parse_full_str("246781312"), Err(RgCreationError::MissingEmitterOrg));
+        assert_eq!(
+            Rg::parse_full_str(" 24.678.1A1-2 SSP/SP"),
+            Err(RgCreationError::InvalidRgStringFormat(FormatMismatch { position: 9, found: Some('A'), input_length: 20 }))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn emitter_org() {
+        use crate::{EmitterOrg, Rg, UF};
+        use crate::rg::Conselho;
+
+        let mut emitter_orgs = vec![
+            EmitterOrg::IFP, EmitterOrg::PoliciaFederal, EmitterOrg::CartorioCivil, EmitterOrg::Exercito,
+            EmitterOrg::Marinha, EmitterOrg::Aeronautica, EmitterOrg::DPMAF, EmitterOrg::CGPI,
+        ];
+        for uf in UF::ALL.iter().copied() {
+            emitter_orgs.extend_from_slice(&[
+                EmitterOrg::SSP(uf), EmitterOrg::SESP(uf), EmitterOrg::SDS(uf), EmitterOrg::SEJUSP(uf),
+                EmitterOrg::PC(uf), EmitterOrg::IIPC(uf), EmitterOrg::DETRAN(uf),
+            ]);
+            emitter_orgs.extend(Conselho::ALL.iter().map(|conselho| EmitterOrg::Conselho(*conselho, uf)));
+        }
+
+        for emitter_org in emitter_orgs.iter() {
+            assert_eq!(format!("{}", emitter_org).parse().as_ref(), Ok(emitter_org), "{}", emitter_org);
+            assert_eq!(format!("{:#}", emitter_org).parse().as_ref(), Ok(emitter_org), "{:#}", emitter_org);
+        }
+
+        for (s, emitter_org) in [
+            ("SESP/PR", EmitterOrg::SESP(UF::PR)),
+            ("sds-pe", EmitterOrg::SDS(UF::PE)),
+            ("PC MG", EmitterOrg::PC(UF::MG)),
+            ("IFP", EmitterOrg::IFP),
+            ("IFP/RJ", EmitterOrg::IFP),
+            ("OAB/SP", EmitterOrg::Conselho(Conselho::OAB, UF::SP)),
+            ("CRMV-RS", EmitterOrg::Conselho(Conselho::CRMV, UF::RS)),
+            ("CRM/RS", EmitterOrg::Conselho(Conselho::CRM, UF::RS)),
+            ("FAB", EmitterOrg::Aeronautica),
+            ("Exercito", EmitterOrg::Exercito),
+            ("Polícia Federal", EmitterOrg::PoliciaFederal),
+            ("Cartório Civil", EmitterOrg::CartorioCivil),
+            ("SSP", EmitterOrg::Other("SSP".to_string())),
+        ].iter() {
+            assert_eq!(s.parse().as_ref(), Ok(emitter_org), "{}", s);
+        }
+
+        #[allow(deprecated)]
+        let misspelled = EmitterOrg::PoliciaFedaral;
+        assert_eq!(misspelled, EmitterOrg::PoliciaFederal);
+        assert_eq!(EmitterOrg::PoliciaFederal.to_string(), "Polícia Federal");
+        assert_eq!(Rg::new("123456", EmitterOrg::CartorioCivil).to_string(), "123456 Cartório Civil");
+        assert_eq!(EmitterOrg::IFP.uf(), Some(UF::RJ));
+        assert_eq!(EmitterOrg::Marinha.uf(), None);
+        assert_eq!(format!("{:#}", EmitterOrg::Conselho(Conselho::OAB, UF::SP)), "Ordem dos Advogados do Brasil - SP");
+        assert_eq!(format!("{:#}", EmitterOrg::Other("SPTC".to_string())), "SPTC");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rg_validation() {
+        use crate::{EmitterOrg, Rg};
+        use crate::EmitterOrg::SSP;
+        use crate::UF::{MG, RJ, RS, SP};
+        use crate::error::FormatMismatch;
+        use crate::rg::RgCreationError;
+
+        assert_eq!(Rg::parse_str("24.678.131-2", SSP(SP)), Ok(Rg::new("246781312", SSP(SP))));
+        assert_eq!(Rg::parse_str("10000006x", SSP(SP)).map(|rg| rg.code), Ok("10000006X".to_string()));
+        assert_eq!(Rg::parse_str("12.345.678-9", SSP(RJ)).map(|rg| rg.code), Ok("123456789".to_string()));
+        assert_eq!(Rg::parse_str("mg-12.345.678", SSP(MG)).map(|rg| rg.code), Ok("MG12345678".to_string()));
+        assert!(Rg::parse_str("1234567890", SSP(RS)).is_ok());
+        assert_eq!(Rg::parse_str("1.234.567-2", SSP(SP)).map(|rg| rg.code), Ok("12345672".to_string()));
+        assert_eq!(Rg::parse_str("5.678-9", SSP(SP)).map(|rg| rg.code), Ok("56789".to_string()));
+        assert!(Rg::parse_str("A8974B-X", EmitterOrg::PoliciaFederal).is_ok());
+        assert!(Rg::parse_full_str("12.345.678-9 IFP/RJ").is_ok());
+        assert!(Rg::parse_full_str("12.345.678-9 DETRAN/RJ").is_ok());
+        assert!(Rg::parse_full_str("12.345.678 IFP/RJ").is_ok());
+        assert!(Rg::parse_full_str("MG-12.345.678 PC/MG").is_ok());
+
+        assert_eq!(
+            Rg::parse_str("24.678.131-3", SSP(SP)),
+            Err(RgCreationError::InvalidCheckDigit { expected: '2', found: '3' })
+        );
+        assert_eq!(
+            Rg::parse_str("24.678.1A1-2", SSP(SP)),
+            Err(RgCreationError::InvalidRgStringFormat(FormatMismatch { position: 8, found: Some('A'), input_length: 12 }))
+        );
+        assert_eq!(
+            Rg::parse_str("24.678.131-2*", SSP(SP)),
+            Err(RgCreationError::InvalidRgStringFormat(FormatMismatch { position: 12, found: Some('*'), input_length: 13 }))
+        );
+        assert_eq!(
+            Rg::parse_str("1.234.567-0", SSP(SP)),
+            Err(RgCreationError::InvalidCheckDigit { expected: '2', found: '0' })
+        );
+        assert_eq!(
+            Rg::parse_str("678-9", SSP(SP)),
+            Err(RgCreationError::InvalidRgLength { min: 5, max: 9, found: 4 })
+        );
+        assert_eq!(
+            Rg::parse_str("12.345", SSP(MG)),
+            Err(RgCreationError::InvalidRgLength { min: 6, max: 8, found: 5 })
+        );
+        assert_eq!(
+            Rg::parse_full_str("12.345 IFP/RJ"),
+            Err(RgCreationError::InvalidRgLength { min: 6, max: 9, found: 5 })
+        );
+        assert_eq!(
+            Rg::parse_str("12.345.678", SSP(RJ)),
+            Err(RgCreationError::InvalidRgLength { min: 9, max: 9, found: 8 })
+        );
+        assert_eq!(
+            Rg::parse_str("12.345.67A-9", EmitterOrg::DETRAN(RJ)),
+            Err(RgCreationError::InvalidRgStringFormat(FormatMismatch { position: 9, found: Some('A'), input_length: 12 }))
+        );
+        assert_eq!(
+            Rg::parse_str("12.345", EmitterOrg::PC(MG)),
+            Err(RgCreationError::InvalidRgLength { min: 6, max: 8, found: 5 })
+        );
+        assert_eq!(
+            Rg::new("24.678.131-3", SSP(SP)).validate().unwrap_err().to_string(),
+            "invalid RG: check digit should be 2, found 3"
+        );
+        assert_eq!(
+            Rg::new("123", SSP(RJ)).validate().unwrap_err().to_string(),
+            "invalid RG length, expected 9 characters, found 3"
+        );
+    }
 }
@@ -34,7 +34,7 @@ macro_rules! convert_to_u8 {
     };
 }
 
-/// Converts each item of `$expr` to [`String`] and then join all them into a single [`String`]
+/// Converts each item of `$expr` to `String` and then join all them into a single `String`
 /// without any separator.
 #[macro_export]
 macro_rules! join_to_string {
@@ -42,3 +42,59 @@ macro_rules! join_to_string {
         $exp.iter().map(|d| d.to_string()).collect::<String>()
     };
 }
+
+/// Creates a [`Cpf`](crate::Cpf) from a string literal validated at compile time.
+///
+/// The string must be in one of the formats accepted by [`Cpf::parse_str`](crate::Cpf::parse_str),
+/// any invalid Cpf fails the compilation.
+///
+/// # Example
+///
+/// ```
+/// use validbr::{cpf, Cpf};
+///
+/// const CPF: Cpf = cpf!("123.456.789-09");
+/// assert_eq!(CPF, Cpf { digits: [1, 2, 3, 4, 5, 6, 7, 8, 9], verifier_digits: [0, 9]});
+/// assert_eq!(cpf!("12345678909"), CPF);
+/// ```
+///
+/// ```compile_fail
+/// use validbr::cpf;
+///
+/// let cpf = cpf!("123.456.789-10");
+/// ```
+#[macro_export]
+macro_rules! cpf {
+    ($cpf:expr) => {{
+        const CPF: $crate::Cpf = $crate::Cpf::parse_const($cpf);
+        CPF
+    }};
+}
+
+/// Creates a [`Cnpj`](crate::Cnpj) from a string literal validated at compile time.
+///
+/// The string must be in one of the formats accepted by [`Cnpj::parse_str`](crate::Cnpj::parse_str),
+/// any invalid Cnpj fails the compilation.
+///
+/// # Example
+///
+/// ```
+/// use validbr::{cnpj, Cnpj};
+///
+/// const CNPJ: Cnpj = cnpj!("12.345.678/0001-95");
+/// assert_eq!(CNPJ, Cnpj { digits: [1, 2, 3, 4, 5, 6, 7, 8], branch_digits: [0, 0, 0, 1], verifier_digits: [9, 5]});
+/// assert_eq!(cnpj!("12ABC34501DE35"), Cnpj::parse_str("12.ABC.345/01DE-35").unwrap());
+/// ```
+///
+/// ```compile_fail
+/// use validbr::cnpj;
+///
+/// let cnpj = cnpj!("12.345.678/0001-96");
+/// ```
+#[macro_export]
+macro_rules! cnpj {
+    ($cnpj:expr) => {{
+        const CNPJ: $crate::Cnpj = $crate::Cnpj::parse_const($cnpj);
+        CNPJ
+    }};
+}
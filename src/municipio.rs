@@ -0,0 +1,249 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # Municipios
+//!
+//! Brazilian municipalities identified by the 7 digits code assigned by IBGE (Instituto
+//! Brasileiro de Geografia e Estatística). The first two digits are the IBGE code of the
+//! [`UF`](crate::UF), the next four identify the municipality and the last one is a verifier
+//! digit, see [`calculate_verifier_digit`](crate::municipio::calculate_verifier_digit).
+//!
+//! Municipalities are resolved through a table generated by the build script from the IBGE DTB
+//! (Divisão Territorial Brasileira) in `data/municipios.csv`, which holds all the 5,571
+//! municipalities published by IBGE as of September 2026. The `VALIDBR_MUNICIPIO_SOURCE`
+//! environment variable replaces it by a more recent export, see `build.rs` for the table format.
+//! Valid codes which are not in the table, such as of municipalities created afterwards, are
+//! reported as [`MunicipioCreationError::NotFound`](crate::municipio::MunicipioCreationError::NotFound).
+//!
+//! # Example
+//!
+//! ```
+//! use validbr::{Municipio, UF};
+//!
+//! let sao_paulo = Municipio::from_code(3550308).unwrap();
+//! assert_eq!(sao_paulo.name, "São Paulo");
+//! assert!(sao_paulo.capital);
+//! assert_eq!(Municipio::find(&UF::SP, "sao paulo"), Some(sao_paulo));
+//! ```
+mod table;
+
+use crate::{Municipio, UF};
+use crate::error::{impl_error, write_message, write_with_details, Language, LocalizedError, VerifierDigitsMismatch};
+use crate::parse::eq_ignore_accents;
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Formatter;
+use table::MUNICIPIOS;
+
+/// IBGE codes assigned before the verifier digit was standardized, which do not pass the
+/// verifier digit check but are valid.
+pub const VERIFIER_DIGIT_EXCEPTIONS: [u32; 9] = [
+    2201919, // Bom Princípio do Piauí - PI
+    2201988, // Brejo do Piauí - PI
+    2202251, // Canavieira - PI
+    2611533, // Quixaba - PE
+    3117836, // Cônego Marinho - MG
+    3152131, // Ponto Chique - MG
+    4305871, // Coronel Barros - RS
+    5203939, // Buriti de Goiás - GO
+    5203962, // Buritinópolis - GO
+];
+
+/// Occurs when a Municipio could not be resolved from an IBGE code.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum MunicipioCreationError {
+    /// When provided code does not have 7 digits.
+    CodeOutOfRange,
+    /// When the first two digits of provided code are not the IBGE code of an UF.
+    UnknownUf,
+    /// When the verifier digit of provided code is not valid.
+    InvalidVerifierDigit(VerifierDigitsMismatch<1>),
+    /// When provided code is valid but the Municipio is not in the bundled table.
+    NotFound,
+}
+
+impl LocalizedError for MunicipioCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            MunicipioCreationError::CodeOutOfRange => write_message(
+                f,
+                language,
+                "IBGE municipality code must have 7 digits",
+                "o código IBGE do município deve possuir 7 dígitos",
+            ),
+            MunicipioCreationError::UnknownUf => write_message(
+                f,
+                language,
+                "IBGE municipality code does not start with the code of an UF",
+                "o código IBGE do município não começa com o código de uma UF",
+            ),
+            MunicipioCreationError::InvalidVerifierDigit(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid IBGE municipality code",
+                "código IBGE de município inválido",
+                mismatch,
+            ),
+            MunicipioCreationError::NotFound => write_message(
+                f,
+                language,
+                "municipality not found",
+                "município não encontrado",
+            ),
+        }
+    }
+}
+
+impl_error!(MunicipioCreationError);
+
+/// Calculates the verifier digit of an IBGE municipality code from its first six digits.
+///
+/// The digits are multiplied by the weights 1 and 2, alternately, the digits of each product are
+/// summed and the verifier digit is the amount left to the next multiple of 10.
+///
+/// # Example
+///
+/// ```
+/// use validbr::municipio::calculate_verifier_digit;
+///
+/// assert_eq!(calculate_verifier_digit([3, 5, 5, 0, 3, 0]), 8);
+/// ```
+pub const fn calculate_verifier_digit(digits: [u8; 6]) -> u8 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        let product = digits[i] * (1 + (i % 2) as u8);
+        sum += product / 10 + product % 10;
+        i += 1;
+    }
+
+    (10 - sum % 10) % 10
+}
+
+/// Validates the IBGE municipality `code` and returns the UF it belongs to.
+///
+/// # Example
+///
+/// ```
+/// use validbr::UF;
+/// use validbr::municipio::{validate_code, MunicipioCreationError};
+///
+/// assert_eq!(validate_code(3550308), Ok(UF::SP));
+/// assert_eq!(validate_code(2201919), Ok(UF::PI));
+/// assert_eq!(validate_code(355030), Err(MunicipioCreationError::CodeOutOfRange));
+/// assert!(matches!(validate_code(3550309), Err(MunicipioCreationError::InvalidVerifierDigit(_))));
+/// ```
+pub fn validate_code(code: u32) -> Result<UF, MunicipioCreationError> {
+    if !(1_000_000..=9_999_999).contains(&code) {
+        return Err(MunicipioCreationError::CodeOutOfRange)
+    }
+
+    let uf = UF::try_from((code / 100_000) as u8).map_err(|_| MunicipioCreationError::UnknownUf)?;
+
+    let mut digits = [0u8; 6];
+    let mut rest = code / 10;
+    for digit in digits.iter_mut().rev() {
+        *digit = (rest % 10) as u8;
+        rest /= 10;
+    }
+
+    let expected = calculate_verifier_digit(digits);
+    let found = (code % 10) as u8;
+    if expected != found && !VERIFIER_DIGIT_EXCEPTIONS.contains(&code) {
+        return Err(MunicipioCreationError::InvalidVerifierDigit(VerifierDigitsMismatch {
+            expected: [expected],
+            found: [found],
+        }))
+    }
+
+    Ok(uf)
+}
+
+impl Municipio {
+    /// Resolves the Municipio of the IBGE `code` from the bundled table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{Municipio, UF};
+    /// use validbr::municipio::MunicipioCreationError;
+    ///
+    /// let campinas = Municipio::from_code(3509502).unwrap();
+    /// assert_eq!((campinas.name, &campinas.uf, campinas.capital), ("Campinas", &UF::SP, false));
+    /// assert_eq!(Municipio::from_code(9950308), Err(MunicipioCreationError::UnknownUf));
+    /// ```
+    pub fn from_code(code: u32) -> Result<&'static Municipio, MunicipioCreationError> {
+        validate_code(code)?;
+
+        MUNICIPIOS
+            .binary_search_by_key(&code, |municipio| municipio.code)
+            .map(|index| &MUNICIPIOS[index])
+            .map_err(|_| MunicipioCreationError::NotFound)
+    }
+
+    /// Finds the Municipio of the `uf` named `name`, ignoring case and accents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{Municipio, UF};
+    ///
+    /// assert_eq!(Municipio::find(&UF::PR, "londrina").unwrap().code, 4113700);
+    /// assert_eq!(Municipio::find(&UF::SP, "NITEROI"), None);
+    /// assert_eq!(Municipio::find(&UF::RJ, "NITEROI").unwrap().name, "Niterói");
+    /// ```
+    pub fn find(uf: &UF, name: &str) -> Option<&'static Municipio> {
+        let name = name.trim();
+        Municipio::of(uf).find(|municipio| eq_ignore_accents(municipio.name, name))
+    }
+
+    /// Iterates over all the Municipios of the `uf`, sorted by IBGE code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{Municipio, UF};
+    ///
+    /// let names: Vec<&str> = Municipio::of(&UF::MG).map(|municipio| municipio.name).collect();
+    /// assert!(names.contains(&"Belo Horizonte"));
+    /// assert!(Municipio::of(&UF::MG).all(|municipio| municipio.uf == UF::MG));
+    /// ```
+    pub fn of(uf: &UF) -> impl Iterator<Item = &'static Municipio> {
+        let uf = *uf;
+        MUNICIPIOS.iter().filter(move |municipio| municipio.uf == uf)
+    }
+
+    /// All the Municipios of the bundled table, sorted by IBGE code.
+    pub fn all() -> &'static [Municipio] {
+        MUNICIPIOS
+    }
+}
+
+/// Formats the Municipio name followed by its UF, for example: `São Paulo - SP`.
+impl fmt::Display for Municipio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} - {}", self.name, self.uf)
+    }
+}
@@ -0,0 +1,306 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # Parse options
+//!
+//! This module provides the [`ParseOptions`](crate::parse::ParseOptions) used by [`Cpf::parse_str_with`](crate::Cpf::parse_str_with)
+//! and [`Cnpj::parse_str_with`](crate::Cnpj::parse_str_with) to configure how much formatting noise
+//! is tolerated when parsing a document string.
+use crate::error::FormatMismatch;
+
+/// Options used to parse document strings, such as Cpf and Cnpj.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Cpf;
+/// use validbr::parse::ParseOptions;
+///
+/// let options = ParseOptions { pad_leading_zeros: true, ..ParseOptions::lenient() };
+/// let cpf = Cpf::parse_str_with(" 45.321.876-80\n", &options);
+/// assert_eq!(cpf, Ok(Cpf { digits: [0, 4, 5, 3, 2, 1, 8, 7, 6], verifier_digits: [8, 0]}));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct ParseOptions {
+    /// Whether whitespaces and separators (`.`, `-`, `/`, `\`, `_` and `,`) are accepted anywhere in
+    /// the string, as well as lowercase letters for alphanumeric documents.
+    ///
+    /// When `false`, only the well known formatted representation and the only digits
+    /// representation are accepted, and the entire string must match them.
+    pub lenient: bool,
+    /// Whether documents with less digits than expected are padded with leading zeros, which
+    /// commonly happens to documents stored as numbers (e.g. in spreadsheets).
+    ///
+    /// In strict mode, only the only digits representation is padded.
+    pub pad_leading_zeros: bool,
+    /// Whether documents with all digits repeated, such as `000.000.000-00`, are accepted.
+    pub allow_repeated_digits: bool,
+}
+
+impl ParseOptions {
+    /// Options which only accept the exact well known formats of each document.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cpf;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// assert!(Cpf::parse_str_with("123.456.789-09", &ParseOptions::strict()).is_ok());
+    /// assert!(Cpf::parse_str_with("x123.456.789-09y", &ParseOptions::strict()).is_err());
+    /// assert!(Cpf::parse_str_with("123 456 789 09", &ParseOptions::strict()).is_err());
+    /// ```
+    pub const fn strict() -> ParseOptions {
+        ParseOptions {
+            lenient: false,
+            pad_leading_zeros: false,
+            allow_repeated_digits: false,
+        }
+    }
+
+    /// Options which accept whitespaces and separators anywhere in the document string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cpf;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// assert!(Cpf::parse_str_with("123 456 789 09", &ParseOptions::lenient()).is_ok());
+    /// assert!(Cpf::parse_str_with("123.456.789/09", &ParseOptions::lenient()).is_ok());
+    /// assert!(Cpf::parse_str_with("  12345678909\n", &ParseOptions::lenient()).is_ok());
+    /// assert!(Cpf::parse_str_with("x123.456.789-09y", &ParseOptions::lenient()).is_err());
+    /// ```
+    pub const fn lenient() -> ParseOptions {
+        ParseOptions {
+            lenient: true,
+            pad_leading_zeros: false,
+            allow_repeated_digits: false,
+        }
+    }
+
+    /// Options which only accept the exact well known formats of each document, but pad
+    /// only digits documents which are missing leading zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Cpf;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let cpf = Cpf::parse_str_with("4532187680", &ParseOptions::padded());
+    /// assert_eq!(cpf, Ok(Cpf { digits: [0, 4, 5, 3, 2, 1, 8, 7, 6], verifier_digits: [8, 0]}));
+    /// ```
+    pub const fn padded() -> ParseOptions {
+        ParseOptions {
+            lenient: false,
+            pad_leading_zeros: true,
+            allow_repeated_digits: false,
+        }
+    }
+}
+
+/// Strict parsing is the default.
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::strict()
+    }
+}
+
+/// Failure of [`normalize`], which is converted to the creation error of each document.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum FormatError {
+    /// The string does not match any accepted format.
+    InvalidFormat(FormatMismatch),
+    /// The string has less significant characters than expected.
+    Short(FormatMismatch),
+}
+
+/// Whether `c` is a separator ignored by lenient parsing.
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '.' | '-' | '/' | '\\' | '_' | ',')
+}
+
+/// Whether `mask` byte is a placeholder for a significant character, `#` for digits, `A` for
+/// uppercase letters and `@` for digits or uppercase letters.
+const fn is_placeholder(m: u8) -> bool {
+    m == b'#' || m == b'A' || m == b'@'
+}
+
+/// Whether `c` could replace the `placeholder` of a mask.
+const fn accepts(placeholder: u8, c: char) -> bool {
+    match placeholder {
+        b'#' => c.is_ascii_digit(),
+        b'A' => c.is_ascii_uppercase(),
+        b'@' => c.is_ascii_digit() || c.is_ascii_uppercase(),
+        _ => false,
+    }
+}
+
+/// Extracts the `N` significant characters of `input` according to `options`, as ASCII bytes.
+///
+/// `mask` is the well known formatted representation of the document, in which `#` is a
+/// placeholder for a digit, `A` for an uppercase letter, `@` for a digit or an uppercase letter,
+/// and any other character is a literal separator, for example `###.###.###-##` for Cpf. The mask
+/// must have exactly `N` placeholders.
+pub(crate) fn normalize<const N: usize>(
+    input: &str,
+    options: &ParseOptions,
+    mask: &str,
+) -> Result<[u8; N], FormatError> {
+    let mut normalized = [b'0'; N];
+    normalize_into(input, options, mask, &mut normalized)?;
+    Ok(normalized)
+}
+
+/// Extracts the significant characters of `input` to `normalized`, which length is the number of
+/// placeholders of `mask`, see [`normalize`].
+///
+/// Used by documents which length is only known at runtime.
+pub(crate) fn normalize_into(
+    input: &str,
+    options: &ParseOptions,
+    mask: &str,
+    normalized: &mut [u8],
+) -> Result<(), FormatError> {
+    let n = normalized.len();
+    let mismatch = |position: usize, found: Option<char>| FormatMismatch {
+        position,
+        found,
+        input_length: input.len(),
+    };
+
+    normalized.fill(b'0');
+    let has_literal = |c: char| !c.is_ascii_alphanumeric() && mask.contains(c);
+
+    if !options.lenient && input.chars().any(has_literal) {
+        // Formatted representation, which must match the entire mask.
+        let mut index = 0;
+        let mut chars = input.char_indices();
+        for m in mask.bytes() {
+            match chars.next() {
+                Some((position, c)) => {
+                    if is_placeholder(m) && accepts(m, c) {
+                        normalized[index] = c as u8;
+                        index += 1;
+                    } else if is_placeholder(m) || c != m as char {
+                        return Err(FormatError::InvalidFormat(mismatch(position, Some(c))))
+                    }
+                }
+                None => return Err(FormatError::InvalidFormat(mismatch(input.len(), None))),
+            }
+        }
+
+        return match chars.next() {
+            Some((position, c)) => Err(FormatError::InvalidFormat(mismatch(position, Some(c)))),
+            None => Ok(()),
+        }
+    }
+
+    let is_significant = |c: char| !options.lenient || !is_separator(c);
+    let significant_count = input.chars().filter(|c| is_significant(*c)).count();
+    let missing = if options.pad_leading_zeros && significant_count > 0 && significant_count < n {
+        n - significant_count
+    } else {
+        0
+    };
+
+    let mut index = missing;
+    let mut placeholders = mask.bytes().filter(|m| is_placeholder(*m)).skip(missing);
+    for (position, c) in input.char_indices().filter(|(_, c)| is_significant(*c)) {
+        let upper = if options.lenient { c.to_ascii_uppercase() } else { c };
+        match placeholders.next() {
+            Some(placeholder) if accepts(placeholder, upper) => {
+                normalized[index] = upper as u8;
+                index += 1;
+            }
+            _ => return Err(FormatError::InvalidFormat(mismatch(position, Some(c)))),
+        }
+    }
+
+    if index < n {
+        Err(FormatError::Short(mismatch(input.len(), None)))
+    } else {
+        Ok(())
+    }
+}
+
+/// Extracts the `N` significant characters of `input` in a const context, accepting only the
+/// formats accepted by [`ParseOptions::strict`]: the entire `mask` or exactly `N` significant
+/// characters.
+///
+/// Returns [`None`] if `input` does not match any of these formats, see [`normalize`] for the
+/// `mask` syntax.
+pub(crate) const fn normalize_const<const N: usize>(input: &str, mask: &str) -> Option<[u8; N]> {
+    let input = input.as_bytes();
+    let mask = mask.as_bytes();
+    let formatted = input.len() == mask.len();
+
+    if !formatted && input.len() != N {
+        return None
+    }
+
+    let mut normalized = [b'0'; N];
+    let mut index = 0;
+    let mut pos = 0;
+    while pos < mask.len() {
+        let m = mask[pos];
+        if is_placeholder(m) {
+            let c = if formatted { input[pos] } else { input[index] };
+            if !accepts(m, c as char) {
+                return None
+            }
+            normalized[index] = c;
+            index += 1;
+        } else if formatted && input[pos] != m {
+            return None
+        }
+        pos += 1;
+    }
+
+    Some(normalized)
+}
+
+/// Compares `a` and `b` ignoring case and the accents of Portuguese letters, thus `São Paulo`,
+/// `sao paulo` and `SAO PAULO` are equal.
+pub(crate) fn eq_ignore_accents(a: &str, b: &str) -> bool {
+    fn fold(c: char) -> char {
+        match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            c => c,
+        }
+    }
+
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .map(fold)
+        .eq(b.chars().flat_map(char::to_lowercase).map(fold))
+}
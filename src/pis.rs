@@ -0,0 +1,383 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # PIS
+//!
+//! This module provides utility for constructing and validating PIS (Programa de Integração
+//! Social) numbers, which share the same format and verifier digit with PASEP, NIT and NIS
+//! numbers. If a PIS was successfully constructed with [`Pis::new`] or [`Pis::parse_str`] it
+//! means that the PIS is valid.
+use crate::Pis;
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError, VerifierDigitsMismatch};
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+use core::fmt::Formatter;
+#[cfg(feature = "rand")]
+use {
+    rand::distributions::{Distribution, Standard, Uniform},
+    rand::Rng,
+};
+
+/// Mask of the Pis format, see [`parse::normalize`].
+const PIS_MASK: &str = "###.#####.##-#";
+
+/// Weights of the 10 digits of Pis in the verifier digit calculation.
+const WEIGHTS: [u16; 10] = [3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Formats Pis as `###.#####.##-#`.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Pis;
+/// let pis = Pis::parse_str("12012345672").expect("Invalid pis.");
+/// assert_eq!(format!("{}", pis), "120.12345.67-2")
+/// ```
+impl fmt::Display for Pis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, digit) in self.digits.iter().enumerate() {
+            if i == 3 || i == 8 {
+                f.write_str(".")?;
+            }
+            write!(f, "{}", digit)?;
+        }
+        write!(f, "-{}", self.verifier_digit)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum PisCreationError {
+    /// When provided Pis digits could not be validated against its verifier digit, in other
+    /// words, when provided Pis is not valid.
+    ///
+    /// Holds the expected and the provided verifier digit.
+    InvalidPisDigits(VerifierDigitsMismatch<1>),
+    /// When provided Pis string is not a valid Pis format.
+    ///
+    /// Supported Pis formats are:
+    /// - 000.00000.00-0
+    /// - 00000000000
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidPisStringFormat(FormatMismatch),
+    /// When provided Pis string has less digits than a Pis.
+    ShortPisString(FormatMismatch),
+    /// When provided numbers for digits (pis digits or verifier digit)
+    /// are out of bounds, in other words, they are not respecting the range of `0..=9`.
+    DigitsOutOfBounds,
+    /// When provided number has more than 11 digits, thus could not be converted to a Pis.
+    NumberOutOfRange,
+    /// When all digits of provided Pis are the same, for example `000.00000.00-0`.
+    ///
+    /// These Pis pass the verifier digit check, but are known to be invalid. Use
+    /// [`Pis::new_lenient`] to accept them.
+    RepeatedDigits,
+}
+
+impl LocalizedError for PisCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            PisCreationError::InvalidPisDigits(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid PIS",
+                "PIS inválido",
+                mismatch,
+            ),
+            PisCreationError::InvalidPisStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid PIS format, expected ###.#####.##-# or ###########",
+                "formato de PIS inválido, esperado ###.#####.##-# ou ###########",
+                mismatch,
+            ),
+            PisCreationError::ShortPisString(mismatch) => write_with_details(
+                f,
+                language,
+                "PIS has less than 11 digits",
+                "PIS possui menos de 11 dígitos",
+                mismatch,
+            ),
+            PisCreationError::DigitsOutOfBounds => write_message(
+                f,
+                language,
+                "PIS digits must be in the range of 0 to 9",
+                "os dígitos do PIS devem estar entre 0 e 9",
+            ),
+            PisCreationError::NumberOutOfRange => write_message(
+                f,
+                language,
+                "number has more than 11 digits and could not be converted to a PIS",
+                "o número possui mais de 11 dígitos e não pode ser convertido em PIS",
+            ),
+            PisCreationError::RepeatedDigits => write_message(
+                f,
+                language,
+                "invalid PIS: all digits are the same",
+                "PIS inválido: todos os dígitos são iguais",
+            ),
+        }
+    }
+}
+
+impl_error!(PisCreationError);
+
+impl Pis {
+    /// Creates a new Pis if the provided `[digits]` and `verifier_digit` are valid.
+    ///
+    /// Pis with all digits repeated, such as `000.00000.00-0`, are rejected with
+    /// [`PisCreationError::RepeatedDigits`], see [`Pis::new_lenient`] for a constructor
+    /// which accepts them.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Pis;
+    /// use validbr::pis::PisCreationError;
+    /// use validbr::error::VerifierDigitsMismatch;
+    ///
+    /// let pis = Pis::new([1, 2, 0, 1, 2, 3, 4, 5, 6, 7], 2); // Valid PIS
+    /// assert!(pis.is_ok());
+    ///
+    /// let pis = Pis::new([1, 2, 0, 1, 2, 3, 4, 5, 6, 7], 3); // Invalid PIS
+    /// assert_eq!(pis, Err(PisCreationError::InvalidPisDigits(VerifierDigitsMismatch { expected: [2], found: [3] })));
+    /// ```
+    pub fn new(digits: [u8; 10], verifier_digit: u8) -> Result<Pis, PisCreationError> {
+        Pis::create(digits, verifier_digit, false)
+    }
+
+    /// Creates a new Pis if the provided `[digits]` and `verifier_digit` are valid, accepting
+    /// Pis with all digits repeated, such as `000.00000.00-0`.
+    ///
+    /// This constructor is meant for importing legacy data which must keep these Pis, prefer
+    /// [`Pis::new`] otherwise.
+    pub fn new_lenient(digits: [u8; 10], verifier_digit: u8) -> Result<Pis, PisCreationError> {
+        Pis::create(digits, verifier_digit, true)
+    }
+
+    /// Checks whether all digits of this Pis are the same, which is only possible for Pis
+    /// created through [`Pis::new_lenient`].
+    pub fn has_repeated_digits(&self) -> bool {
+        has_repeated_digits(&self.digits, self.verifier_digit)
+    }
+
+    fn create(digits: [u8; 10], verifier_digit: u8, allow_repeated_digits: bool) -> Result<Pis, PisCreationError> {
+        if !digits.iter().all(|d| *d <= 9) || verifier_digit > 9 {
+            return Err(PisCreationError::DigitsOutOfBounds)
+        }
+
+        if !allow_repeated_digits && has_repeated_digits(&digits, verifier_digit) {
+            return Err(PisCreationError::RepeatedDigits)
+        }
+
+        let expected = calculate_verifier_digit(digits);
+        if expected == verifier_digit {
+            Ok(Pis {
+                digits,
+                verifier_digit,
+            })
+        } else {
+            Err(PisCreationError::InvalidPisDigits(VerifierDigitsMismatch {
+                expected: [expected],
+                found: [verifier_digit],
+            }))
+        }
+    }
+
+    /// Parses a Pis String to a [`Pis`].
+    ///
+    /// Supported Pis formats are:
+    ///
+    /// - 000.00000.00-0
+    /// - 00000000000
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Pis;
+    /// let pis = Pis::parse_str("120.12345.67-2");
+    /// assert_eq!(pis, Ok(Pis { digits: [1, 2, 0, 1, 2, 3, 4, 5, 6, 7], verifier_digit: 2 }));
+    /// assert_eq!(Pis::parse_str("12012345672"), pis);
+    /// ```
+    pub fn parse_str(pis: &str) -> Result<Pis, PisCreationError> {
+        Pis::parse_str_with(pis, &ParseOptions::strict())
+    }
+
+    /// Parses a Pis String to a [`Pis`] according to the provided parse `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Pis;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let pis = Pis::parse_str_with(" 120.1234.567-2 ", &ParseOptions::lenient());
+    /// assert_eq!(pis, Pis::parse_str("120.12345.67-2"));
+    /// ```
+    pub fn parse_str_with(pis: &str, options: &ParseOptions) -> Result<Pis, PisCreationError> {
+        let normalized: [u8; 11] = parse::normalize(pis, options, PIS_MASK)
+            .map_err(|e| match e {
+                FormatError::InvalidFormat(mismatch) => PisCreationError::InvalidPisStringFormat(mismatch),
+                FormatError::Short(mismatch) => PisCreationError::ShortPisString(mismatch),
+            })?;
+
+        let digits: [u8; 10] = core::array::from_fn(|i| normalized[i] - b'0');
+
+        Pis::create(digits, normalized[10] - b'0', options.allow_repeated_digits)
+    }
+}
+
+/// Parses a Pis String to a [`Pis`], see [`Pis::parse_str`] for supported formats.
+impl FromStr for Pis {
+    type Err = PisCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Pis::parse_str(s)
+    }
+}
+
+/// Parses a Pis String to a [`Pis`], see [`Pis::parse_str`] for supported formats.
+impl TryFrom<&str> for Pis {
+    type Error = PisCreationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Pis::parse_str(value)
+    }
+}
+
+/// Converts a number to a [`Pis`], the number is padded with leading zeros, so numbers stored
+/// without the leading zeros of the Pis are supported.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Pis;
+/// use validbr::pis::PisCreationError;
+/// use std::convert::TryFrom;
+///
+/// let pis = Pis::try_from(12012345672u64);
+/// assert_eq!(pis, Ok(Pis { digits: [1, 2, 0, 1, 2, 3, 4, 5, 6, 7], verifier_digit: 2 }));
+/// assert_eq!(Pis::try_from(120123456720u64), Err(PisCreationError::NumberOutOfRange));
+/// ```
+impl TryFrom<u64> for Pis {
+    type Error = PisCreationError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value > 99_999_999_999 {
+            return Err(PisCreationError::NumberOutOfRange)
+        }
+
+        let mut digits = [0u8; 10];
+        let mut remaining = value / 10;
+        for digit in digits.iter_mut().rev() {
+            *digit = (remaining % 10) as u8;
+            remaining /= 10;
+        }
+
+        Pis::new(digits, (value % 10) as u8)
+    }
+}
+
+/// Converts a [`Pis`] to its number representation, the leading zeros of the Pis are lost in
+/// this representation.
+impl From<&Pis> for u64 {
+    fn from(pis: &Pis) -> Self {
+        pis.digits
+            .iter()
+            .chain(core::iter::once(&pis.verifier_digit))
+            .fold(0u64, |acc, d| acc * 10 + (*d as u64))
+    }
+}
+
+/// Converts a [`Pis`] to its number representation, the leading zeros of the Pis are lost in
+/// this representation.
+impl From<Pis> for u64 {
+    fn from(pis: Pis) -> Self {
+        u64::from(&pis)
+    }
+}
+
+/// Checks whether all `digits` and the `verifier_digit` are the same digit.
+fn has_repeated_digits(digits: &[u8; 10], verifier_digit: u8) -> bool {
+    digits.iter().all(|d| *d == verifier_digit)
+}
+
+/// Calculates the verifier digit of the 10 `digits` of a Pis.
+///
+/// The digits are multiplied by the weights `3, 2, 9, 8, 7, 6, 5, 4, 3, 2`, and a remainder `r`
+/// of the division of the sum by 11 produces the digit `11 - r`, or `0` if `11 - r` is `10` or `11`.
+///
+/// # Example
+///
+/// ```
+/// use validbr::pis::calculate_verifier_digit;
+///
+/// assert_eq!(calculate_verifier_digit([1, 2, 0, 1, 2, 3, 4, 5, 6, 7]), 2);
+/// assert_eq!(calculate_verifier_digit([1, 2, 3, 4, 5, 6, 7, 8, 9, 0]), 0);
+/// ```
+pub const fn calculate_verifier_digit(digits: [u8; 10]) -> u8 {
+    let mut sum: u16 = 0;
+    let mut pos = 0;
+    while pos < 10 {
+        sum += (digits[pos] as u16) * WEIGHTS[pos];
+        pos += 1;
+    }
+
+    match 11 - (sum % 11) as u8 {
+        digit if digit >= 10 => 0,
+        digit => digit,
+    }
+}
+
+/// ## Random PIS Example
+///
+/// ```
+/// use validbr::Pis;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+/// let pis: Pis = rng.gen();
+///
+/// assert_eq!(validbr::pis::calculate_verifier_digit(pis.digits), pis.verifier_digit);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Pis> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Pis {
+        let uniform_int = Uniform::from(0u8..=9u8);
+        loop {
+            let digits: [u8; 10] = core::array::from_fn(|_| rng.sample(uniform_int));
+            let verifier_digit = calculate_verifier_digit(digits);
+
+            // Repeated digits Pis are known to be invalid, so they must be generated again.
+            if !has_repeated_digits(&digits, verifier_digit) {
+                return Pis::new(digits, verifier_digit)
+                    .expect("Generated Pis MUST be valid at this point")
+            }
+        }
+    }
+}
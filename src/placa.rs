@@ -0,0 +1,436 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # Placa
+//!
+//! This module provides utility for parsing and converting vehicle license plates, in both the
+//! old format (`ABC-1234`) and the Mercosul format (`ABC1D23`), see [`PlacaFormat`](crate::placa::PlacaFormat).
+//!
+//! Plates in the old format are converted to the Mercosul format by replacing the second digit by
+//! a letter, from `0` → `A` to `9` → `J`, see [`Placa::to_mercosul`] and [`Placa::to_old`].
+use crate::{Placa, UF};
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError};
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Formatter;
+use core::str::FromStr;
+#[cfg(feature = "rand")]
+use {
+    rand::distributions::{Distribution, Standard, Uniform},
+    rand::Rng,
+};
+
+/// Masks of the Placa formats, see [`parse::normalize`], tried in order. Only old Placa are
+/// formatted with a separator, the fifth character is a letter in Mercosul Placa.
+const PLACA_MASKS: [&str; 2] = ["AAA-####", "AAA#@##"];
+
+/// Index of the character which differs between the old and the Mercosul formats.
+const MERCOSUL_INDEX: usize = 4;
+
+/// Inclusive ranges of letters assigned by DENATRAN to each UF in the first distribution of
+/// the old format.
+///
+/// Ranges distributed after the first one are not listed, so [`Placa::uf`] returns [`None`] for
+/// the letters after `NFB`.
+const UF_RANGES: [(&[u8; 3], &[u8; 3], UF); 27] = [
+    (b"AAA", b"BEZ", UF::PR),
+    (b"BFA", b"GKI", UF::SP),
+    (b"GKJ", b"HOK", UF::MG),
+    (b"HOL", b"HQE", UF::MA),
+    (b"HQF", b"HTW", UF::MS),
+    (b"HTX", b"HZA", UF::CE),
+    (b"HZB", b"IAP", UF::SE),
+    (b"IAQ", b"JDO", UF::RS),
+    (b"JDP", b"JKR", UF::DF),
+    (b"JKS", b"JSZ", UF::BA),
+    (b"JTA", b"JWE", UF::PA),
+    (b"JWF", b"JXY", UF::AM),
+    (b"JXZ", b"KAU", UF::MT),
+    (b"KAV", b"KFC", UF::GO),
+    (b"KFD", b"KME", UF::PE),
+    (b"KMF", b"LVE", UF::RJ),
+    (b"LVF", b"LWQ", UF::PI),
+    (b"LWR", b"MMM", UF::SC),
+    (b"MMN", b"MOW", UF::PB),
+    (b"MOX", b"MTZ", UF::ES),
+    (b"MUA", b"MVK", UF::AL),
+    (b"MVL", b"MXG", UF::TO),
+    (b"MXH", b"MZM", UF::RN),
+    (b"MZN", b"NAG", UF::AC),
+    (b"NAH", b"NBA", UF::RR),
+    (b"NBB", b"NEH", UF::RO),
+    (b"NEI", b"NFB", UF::AP),
+];
+
+/// Formats the Placa in its format, `ABC-1234` for old Placa and `ABC1D23` for Mercosul Placa.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Placa;
+/// assert_eq!(Placa::parse_str("ABC1234").unwrap().to_string(), "ABC-1234");
+/// assert_eq!(Placa::parse_str("ABC1C34").unwrap().to_string(), "ABC1C34");
+/// ```
+impl fmt::Display for Placa {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.characters.iter().enumerate() {
+            if i == 3 && self.format() == PlacaFormat::Old {
+                f.write_str("-")?;
+            }
+            write!(f, "{}", *c as char)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum PlacaCreationError {
+    /// When provided Placa string is not a valid Placa format.
+    ///
+    /// Supported Placa formats are:
+    /// - AAA-0000
+    /// - AAA0000
+    /// - AAA0A00
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidPlacaStringFormat(FormatMismatch),
+    /// When provided Placa string has less characters than a Placa.
+    ShortPlacaString(FormatMismatch),
+    /// When provided characters are not uppercase letters and digits in the positions of
+    /// the old or the Mercosul format.
+    InvalidCharacters,
+}
+
+impl LocalizedError for PlacaCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            PlacaCreationError::InvalidPlacaStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid plate format, expected AAA-0000, AAA0000 or AAA0A00",
+                "formato de placa inválido, esperado AAA-0000, AAA0000 ou AAA0A00",
+                mismatch,
+            ),
+            PlacaCreationError::ShortPlacaString(mismatch) => write_with_details(
+                f,
+                language,
+                "plate has less than 7 characters",
+                "placa possui menos de 7 caracteres",
+                mismatch,
+            ),
+            PlacaCreationError::InvalidCharacters => write_message(
+                f,
+                language,
+                "plate must have 3 letters followed by 4 digits, or a letter as the second digit",
+                "a placa deve ter 3 letras seguidas de 4 dígitos, ou uma letra no lugar do segundo dígito",
+            ),
+        }
+    }
+}
+
+impl_error!(PlacaCreationError);
+
+/// Format of a Placa, identified by its fifth character.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum PlacaFormat {
+    /// Old format, with 3 letters followed by 4 digits, such as `ABC-1234`.
+    Old,
+    /// Mercosul format, adopted since 2018, with a letter in the place of the second digit, such
+    /// as `ABC1D23`.
+    Mercosul,
+}
+
+impl Placa {
+    /// Creates a new Placa if the provided `[characters]` are ASCII uppercase letters and digits
+    /// in the positions of the old or the Mercosul format.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Placa;
+    /// use validbr::placa::PlacaCreationError;
+    ///
+    /// let placa = Placa::new(*b"ABC1D23"); // Valid Mercosul Placa
+    /// assert!(placa.is_ok());
+    ///
+    /// let placa = Placa::new(*b"AB12345"); // Invalid Placa
+    /// assert_eq!(placa, Err(PlacaCreationError::InvalidCharacters));
+    /// ```
+    pub fn new(characters: [u8; 7]) -> Result<Placa, PlacaCreationError> {
+        let valid = characters.iter().enumerate().all(|(i, c)| match i {
+            0..=2 => c.is_ascii_uppercase(),
+            MERCOSUL_INDEX => c.is_ascii_digit() || c.is_ascii_uppercase(),
+            _ => c.is_ascii_digit(),
+        });
+
+        if valid {
+            Ok(Placa { characters })
+        } else {
+            Err(PlacaCreationError::InvalidCharacters)
+        }
+    }
+
+    /// Parses a Placa String to a [`Placa`].
+    ///
+    /// Supported formats are:
+    /// - `AAA-0000` (Old format)
+    /// - `AAA0000` (Old format without separator)
+    /// - `AAA0A00` (Mercosul format)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Placa;
+    /// use validbr::placa::PlacaFormat;
+    ///
+    /// let placa = Placa::parse_str("ABC-1234");
+    /// assert_eq!(placa, Ok(Placa { characters: *b"ABC1234" }));
+    /// assert_eq!(Placa::parse_str("ABC1234"), placa);
+    /// assert_eq!(Placa::parse_str("ABC1C34").unwrap().format(), PlacaFormat::Mercosul);
+    /// assert!(Placa::parse_str("ABC-1C34").is_err());
+    /// ```
+    pub fn parse_str(placa: &str) -> Result<Placa, PlacaCreationError> {
+        Placa::parse_str_with(placa, &ParseOptions::strict())
+    }
+
+    /// Parses a Placa String to a [`Placa`] according to the provided parse `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option, lowercase letters
+    /// are only accepted by lenient parsing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Placa;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let placa = Placa::parse_str_with(" abc 1c34 ", &ParseOptions::lenient());
+    /// assert_eq!(placa, Placa::parse_str("ABC1C34"));
+    /// ```
+    pub fn parse_str_with(placa: &str, options: &ParseOptions) -> Result<Placa, PlacaCreationError> {
+        let mut error = None;
+        for mask in PLACA_MASKS.iter() {
+            match parse::normalize(placa, options, mask) {
+                Ok(characters) => return Placa::new(characters),
+                Err(e) => error = Some(e),
+            }
+        }
+
+        Err(match error.expect("Placa MUST have at least one mask") {
+            FormatError::InvalidFormat(mismatch) => PlacaCreationError::InvalidPlacaStringFormat(mismatch),
+            FormatError::Short(mismatch) => PlacaCreationError::ShortPlacaString(mismatch),
+        })
+    }
+
+    /// Format of this Placa, see [`PlacaFormat`].
+    pub fn format(&self) -> PlacaFormat {
+        if self.characters[MERCOSUL_INDEX].is_ascii_digit() {
+            PlacaFormat::Old
+        } else {
+            PlacaFormat::Mercosul
+        }
+    }
+
+    /// Converts this Placa to the Mercosul format, replacing the second digit by a letter, from
+    /// `0` → `A` to `9` → `J`. Mercosul Placa are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Placa;
+    ///
+    /// let placa = Placa::parse_str("ABC-1234").unwrap();
+    /// assert_eq!(placa.to_mercosul(), Placa::parse_str("ABC1C34").unwrap());
+    /// ```
+    pub fn to_mercosul(&self) -> Placa {
+        let mut characters = self.characters;
+        if characters[MERCOSUL_INDEX].is_ascii_digit() {
+            characters[MERCOSUL_INDEX] = characters[MERCOSUL_INDEX] - b'0' + b'A';
+        }
+        Placa { characters }
+    }
+
+    /// Converts this Placa to the old format, replacing the letter by a digit, from `A` → `0` to
+    /// `J` → `9`. Old Placa are returned unchanged.
+    ///
+    /// Returns [`None`] if the letter is after `J`, which means that the Placa was issued in the
+    /// Mercosul format and has no old representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Placa;
+    ///
+    /// let placa = Placa::parse_str("ABC1C34").unwrap();
+    /// assert_eq!(placa.to_old(), Some(Placa::parse_str("ABC-1234").unwrap()));
+    /// assert_eq!(Placa::parse_str("ABC1K34").unwrap().to_old(), None);
+    /// ```
+    pub fn to_old(&self) -> Option<Placa> {
+        let mut characters = self.characters;
+        match characters[MERCOSUL_INDEX] {
+            c @ b'A'..=b'J' => characters[MERCOSUL_INDEX] = c - b'A' + b'0',
+            c if c.is_ascii_digit() => {}
+            _ => return None,
+        }
+        Some(Placa { characters })
+    }
+
+    /// UF which issued the letters of this old Placa in the first distribution of the old format.
+    ///
+    /// Returns [`None`] for letters distributed afterwards, after `NFB`, and for every Mercosul
+    /// Placa, which are issued from a single national sequence. For a Placa which was actually
+    /// converted from the old format, callers must convert it back with [`Placa::to_old`] before
+    /// calling this method, which is meaningless for Placa issued in the Mercosul format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{Placa, UF};
+    ///
+    /// assert_eq!(Placa::parse_str("BFA-1234").unwrap().uf(), Some(UF::SP));
+    /// assert_eq!(Placa::parse_str("NFC-1234").unwrap().uf(), None);
+    /// assert_eq!(Placa::parse_str("BRA2E19").unwrap().uf(), None);
+    /// ```
+    pub fn uf(&self) -> Option<UF> {
+        if self.format() == PlacaFormat::Mercosul {
+            return None
+        }
+
+        let letters = &self.characters[..3];
+        UF_RANGES
+            .iter()
+            .find(|(start, end, _)| (&start[..]..=&end[..]).contains(&letters))
+            .map(|(_, _, uf)| *uf)
+    }
+}
+
+/// Parses a Placa String to a [`Placa`], see [`Placa::parse_str`] for supported formats.
+impl FromStr for Placa {
+    type Err = PlacaCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Placa::parse_str(s)
+    }
+}
+
+/// Parses a Placa String to a [`Placa`], see [`Placa::parse_str`] for supported formats.
+impl TryFrom<&str> for Placa {
+    type Error = PlacaCreationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Placa::parse_str(value)
+    }
+}
+
+/// Index of the `letters` in the sequence from `AAA` to `ZZZ`.
+#[cfg(feature = "rand")]
+fn letters_index(letters: &[u8; 3]) -> u16 {
+    letters.iter().fold(0, |acc, l| acc * 26 + u16::from(l - b'A'))
+}
+
+/// Samples the characters of an old Placa with letters in the inclusive range of `start` to `end`.
+#[cfg(feature = "rand")]
+fn sample_old<R: Rng + ?Sized>(rng: &mut R, start: &[u8; 3], end: &[u8; 3]) -> [u8; 7] {
+    let index = rng.sample(Uniform::from(letters_index(start)..=letters_index(end)));
+    let digit = Uniform::from(b'0'..=b'9');
+    [
+        b'A' + (index / 676) as u8,
+        b'A' + (index / 26 % 26) as u8,
+        b'A' + (index % 26) as u8,
+        rng.sample(digit),
+        rng.sample(digit),
+        rng.sample(digit),
+        rng.sample(digit),
+    ]
+}
+
+/// ## Random Placa Example
+///
+/// ```
+/// use validbr::Placa;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+/// let placa: Placa = rng.gen();
+///
+/// assert_eq!(Placa::new(placa.characters), Ok(placa));
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Placa> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Placa {
+        if rng.gen() {
+            PlacaFormat::Old.sample(rng)
+        } else {
+            PlacaFormat::Mercosul.sample(rng)
+        }
+    }
+}
+
+/// ## Random Placa of a PlacaFormat Example
+///
+/// ```
+/// use validbr::Placa;
+/// use validbr::placa::PlacaFormat;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+///
+/// let placa: Placa = rng.sample(PlacaFormat::Mercosul);
+/// assert_eq!(placa.format(), PlacaFormat::Mercosul);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Placa> for PlacaFormat {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Placa {
+        let mut characters = sample_old(rng, b"AAA", b"ZZZ");
+        if *self == PlacaFormat::Mercosul {
+            characters[MERCOSUL_INDEX] = rng.sample(Uniform::from(b'A'..=b'Z'));
+        }
+
+        Placa::new(characters).expect("Generated Placa MUST be valid at this point")
+    }
+}
+
+/// ## Random old Placa of an UF Example
+///
+/// ```
+/// use validbr::{Placa, UF};
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+///
+/// let placa: Placa = rng.sample(UF::MG);
+/// assert_eq!(placa.uf(), Some(UF::MG));
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Placa> for UF {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Placa {
+        let (start, end, _) = UF_RANGES
+            .iter()
+            .find(|(_, _, uf)| uf == self)
+            .expect("Every UF MUST have a range of letters");
+
+        Placa::new(sample_old(rng, start, end)).expect("Generated Placa MUST be valid at this point")
+    }
+}
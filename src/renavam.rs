@@ -0,0 +1,345 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # RENAVAM
+//!
+//! This module provides utility for constructing and validating RENAVAM (Registro Nacional de
+//! Veículos Automotores) numbers. If a RENAVAM was successfully constructed with [`Renavam::new`]
+//! or [`Renavam::parse_str`] it means that the RENAVAM is valid.
+//!
+//! RENAVAM numbers had 9 digits until 2013, when they were extended to 11 digits by prefixing the
+//! legacy numbers with `00`, so both forms are accepted by [`Renavam::parse_str`].
+use crate::Renavam;
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError, VerifierDigitsMismatch};
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Formatter;
+use core::str::FromStr;
+#[cfg(feature = "rand")]
+use {
+    rand::distributions::{Distribution, Standard, Uniform},
+    rand::Rng,
+};
+
+/// Mask of the Renavam format, see [`parse::normalize`].
+const RENAVAM_MASK: &str = "###########";
+
+/// Mask of the legacy Renavam format, which is padded with two leading zeros.
+const LEGACY_RENAVAM_MASK: &str = "#########";
+
+/// Formats Renavam as its 11 digits, including the leading zeros of legacy Renavam.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Renavam;
+/// let renavam = Renavam::parse_str("639884962").expect("Invalid renavam.");
+/// assert_eq!(format!("{}", renavam), "00639884962")
+/// ```
+impl fmt::Display for Renavam {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for digit in self.digits.iter() {
+            write!(f, "{}", digit)?;
+        }
+        write!(f, "{}", self.verifier_digit)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum RenavamCreationError {
+    /// When provided Renavam digits could not be validated against its verifier digit, in other
+    /// words, when provided Renavam is not valid.
+    ///
+    /// Holds the expected and the provided verifier digit.
+    InvalidRenavamDigits(VerifierDigitsMismatch<1>),
+    /// When provided Renavam string is not a valid Renavam format.
+    ///
+    /// Supported Renavam formats are:
+    /// - 00000000000
+    /// - 000000000 (legacy)
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidRenavamStringFormat(FormatMismatch),
+    /// When provided Renavam string has less digits than a legacy Renavam, or has 10 digits.
+    ShortRenavamString(FormatMismatch),
+    /// When provided numbers for digits (renavam digits or verifier digit)
+    /// are out of bounds, in other words, they are not respecting the range of `0..=9`.
+    DigitsOutOfBounds,
+    /// When provided number has more than 11 digits, thus could not be converted to a Renavam.
+    NumberOutOfRange,
+}
+
+impl LocalizedError for RenavamCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            RenavamCreationError::InvalidRenavamDigits(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid RENAVAM",
+                "RENAVAM inválido",
+                mismatch,
+            ),
+            RenavamCreationError::InvalidRenavamStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid RENAVAM format, expected 11 or 9 digits",
+                "formato de RENAVAM inválido, esperado 11 ou 9 dígitos",
+                mismatch,
+            ),
+            RenavamCreationError::ShortRenavamString(mismatch) => write_with_details(
+                f,
+                language,
+                "RENAVAM has less than 11 digits",
+                "RENAVAM possui menos de 11 dígitos",
+                mismatch,
+            ),
+            RenavamCreationError::DigitsOutOfBounds => write_message(
+                f,
+                language,
+                "RENAVAM digits must be in the range of 0 to 9",
+                "os dígitos do RENAVAM devem estar entre 0 e 9",
+            ),
+            RenavamCreationError::NumberOutOfRange => write_message(
+                f,
+                language,
+                "number has more than 11 digits and could not be converted to a RENAVAM",
+                "o número possui mais de 11 dígitos e não pode ser convertido em RENAVAM",
+            ),
+        }
+    }
+}
+
+impl_error!(RenavamCreationError);
+
+impl Renavam {
+    /// Creates a new Renavam if the provided `[digits]` and `verifier_digit` are valid.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::Renavam;
+    /// use validbr::renavam::RenavamCreationError;
+    /// use validbr::error::VerifierDigitsMismatch;
+    ///
+    /// let renavam = Renavam::new([0, 0, 6, 3, 9, 8, 8, 4, 9, 6], 2); // Valid RENAVAM
+    /// assert!(renavam.is_ok());
+    ///
+    /// let renavam = Renavam::new([0, 0, 6, 3, 9, 8, 8, 4, 9, 6], 3); // Invalid RENAVAM
+    /// assert_eq!(renavam, Err(RenavamCreationError::InvalidRenavamDigits(VerifierDigitsMismatch { expected: [2], found: [3] })));
+    /// ```
+    pub fn new(digits: [u8; 10], verifier_digit: u8) -> Result<Renavam, RenavamCreationError> {
+        if !digits.iter().all(|d| *d <= 9) || verifier_digit > 9 {
+            return Err(RenavamCreationError::DigitsOutOfBounds)
+        }
+
+        let expected = calculate_verifier_digit(digits);
+        if expected == verifier_digit {
+            Ok(Renavam {
+                digits,
+                verifier_digit,
+            })
+        } else {
+            Err(RenavamCreationError::InvalidRenavamDigits(VerifierDigitsMismatch {
+                expected: [expected],
+                found: [verifier_digit],
+            }))
+        }
+    }
+
+    /// Parses a Renavam String to a [`Renavam`].
+    ///
+    /// Supported Renavam formats are:
+    ///
+    /// - 00000000000
+    /// - 000000000 (legacy, padded with two leading zeros)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Renavam;
+    /// let renavam = Renavam::parse_str("00639884962");
+    /// assert_eq!(renavam, Ok(Renavam { digits: [0, 0, 6, 3, 9, 8, 8, 4, 9, 6], verifier_digit: 2 }));
+    /// assert_eq!(Renavam::parse_str("639884962"), renavam);
+    /// ```
+    pub fn parse_str(renavam: &str) -> Result<Renavam, RenavamCreationError> {
+        Renavam::parse_str_with(renavam, &ParseOptions::strict())
+    }
+
+    /// Parses a Renavam String to a [`Renavam`] according to the provided parse `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Renavam;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let renavam = Renavam::parse_str_with(" 639.884.962 ", &ParseOptions::lenient());
+    /// assert_eq!(renavam, Renavam::parse_str("00639884962"));
+    /// ```
+    pub fn parse_str_with(renavam: &str, options: &ParseOptions) -> Result<Renavam, RenavamCreationError> {
+        let normalized: [u8; 11] = match parse::normalize(renavam, options, RENAVAM_MASK) {
+            Ok(normalized) => normalized,
+            Err(FormatError::InvalidFormat(mismatch)) => {
+                return Err(RenavamCreationError::InvalidRenavamStringFormat(mismatch))
+            }
+            Err(FormatError::Short(mismatch)) => {
+                let legacy: [u8; 9] = parse::normalize(renavam, options, LEGACY_RENAVAM_MASK)
+                    .map_err(|_| RenavamCreationError::ShortRenavamString(mismatch))?;
+                core::array::from_fn(|i| if i < 2 { b'0' } else { legacy[i - 2] })
+            }
+        };
+
+        let digits: [u8; 10] = core::array::from_fn(|i| normalized[i] - b'0');
+
+        Renavam::new(digits, normalized[10] - b'0')
+    }
+
+    /// Whether this Renavam was issued in the legacy 9 digits form, in other words, whether it
+    /// starts with `00`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Renavam;
+    ///
+    /// assert!(Renavam::parse_str("639884962").unwrap().is_legacy());
+    /// assert!(!Renavam::parse_str("12345678900").unwrap().is_legacy());
+    /// ```
+    pub fn is_legacy(&self) -> bool {
+        self.digits[0] == 0 && self.digits[1] == 0
+    }
+}
+
+/// Parses a Renavam String to a [`Renavam`], see [`Renavam::parse_str`] for supported formats.
+impl FromStr for Renavam {
+    type Err = RenavamCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Renavam::parse_str(s)
+    }
+}
+
+/// Parses a Renavam String to a [`Renavam`], see [`Renavam::parse_str`] for supported formats.
+impl TryFrom<&str> for Renavam {
+    type Error = RenavamCreationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Renavam::parse_str(value)
+    }
+}
+
+/// Converts a number to a [`Renavam`], the number is padded with leading zeros, so legacy
+/// Renavam stored as numbers are supported.
+///
+/// # Example
+///
+/// ```
+/// use validbr::Renavam;
+/// use validbr::renavam::RenavamCreationError;
+/// use std::convert::TryFrom;
+///
+/// let renavam = Renavam::try_from(639884962u64);
+/// assert_eq!(renavam, Ok(Renavam { digits: [0, 0, 6, 3, 9, 8, 8, 4, 9, 6], verifier_digit: 2 }));
+/// assert_eq!(Renavam::try_from(123456789000u64), Err(RenavamCreationError::NumberOutOfRange));
+/// ```
+impl TryFrom<u64> for Renavam {
+    type Error = RenavamCreationError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value > 99_999_999_999 {
+            return Err(RenavamCreationError::NumberOutOfRange)
+        }
+
+        let mut digits = [0u8; 10];
+        let mut remaining = value / 10;
+        for digit in digits.iter_mut().rev() {
+            *digit = (remaining % 10) as u8;
+            remaining /= 10;
+        }
+
+        Renavam::new(digits, (value % 10) as u8)
+    }
+}
+
+/// Converts a [`Renavam`] to its number representation, the leading zeros of the Renavam are
+/// lost in this representation.
+impl From<&Renavam> for u64 {
+    fn from(renavam: &Renavam) -> Self {
+        renavam.digits
+            .iter()
+            .chain(core::iter::once(&renavam.verifier_digit))
+            .fold(0u64, |acc, d| acc * 10 + (*d as u64))
+    }
+}
+
+/// Converts a [`Renavam`] to its number representation, the leading zeros of the Renavam are
+/// lost in this representation.
+impl From<Renavam> for u64 {
+    fn from(renavam: Renavam) -> Self {
+        u64::from(&renavam)
+    }
+}
+
+/// Calculates the verifier digit of the 10 `digits` of a Renavam.
+///
+/// The digits are multiplied by the weights `3, 2, 9, 8, 7, 6, 5, 4, 3, 2`, and the remainder of
+/// the division of the sum multiplied by 10 by 11 is the verifier digit, or `0` if the remainder
+/// is `10`. This is the same digit calculated by [`pis::calculate_verifier_digit`](crate::pis::calculate_verifier_digit).
+///
+/// # Example
+///
+/// ```
+/// use validbr::renavam::calculate_verifier_digit;
+///
+/// assert_eq!(calculate_verifier_digit([0, 0, 6, 3, 9, 8, 8, 4, 9, 6]), 2);
+/// assert_eq!(calculate_verifier_digit([1, 2, 3, 4, 5, 6, 7, 8, 9, 0]), 0);
+/// ```
+pub const fn calculate_verifier_digit(digits: [u8; 10]) -> u8 {
+    crate::pis::calculate_verifier_digit(digits)
+}
+
+/// ## Random RENAVAM Example
+///
+/// ```
+/// use validbr::Renavam;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+/// let renavam: Renavam = rng.gen();
+///
+/// assert_eq!(validbr::renavam::calculate_verifier_digit(renavam.digits), renavam.verifier_digit);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<Renavam> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Renavam {
+        let uniform_int = Uniform::from(0u8..=9u8);
+        let digits: [u8; 10] = core::array::from_fn(|_| rng.sample(uniform_int));
+
+        Renavam::new(digits, calculate_verifier_digit(digits))
+            .expect("Generated Renavam MUST be valid at this point")
+    }
+}
@@ -1,6 +1,141 @@
-use crate::{Rg, EmitterOrg};
-use std::fmt;
-use std::fmt::Formatter;
+use crate::{Rg, EmitterOrg, UF};
+use crate::parse::eq_ignore_accents;
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError};
+use core::convert::Infallible;
+use core::fmt;
+use core::fmt::Formatter;
+use core::str::FromStr;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Occurs when a Rg does not match the rules of its emitter organization.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum RgCreationError {
+    /// When provided Rg code has a character which is neither alphanumeric nor a separator
+    /// (`.`, `-`, `/` or space), or a letter where the emitter only accepts digits.
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidRgStringFormat(FormatMismatch),
+    /// When provided Rg code does not have the amount of significant characters expected by the
+    /// emitter organization.
+    InvalidRgLength {
+        /// Minimum amount of significant characters.
+        min: usize,
+        /// Maximum amount of significant characters.
+        max: usize,
+        /// Amount of significant characters of the provided code.
+        found: usize,
+    },
+    /// When provided Rg string does not have an emitter organization after the code.
+    MissingEmitterOrg,
+    /// When the check digit of provided Rg code is not valid.
+    InvalidCheckDigit {
+        /// Check digit calculated from the Rg digits, `X` represents 10.
+        expected: char,
+        /// Check digit provided in the Rg code.
+        found: char,
+    },
+}
+
+impl LocalizedError for RgCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            RgCreationError::InvalidRgStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid RG format",
+                "formato de RG inválido",
+                mismatch,
+            ),
+            RgCreationError::InvalidRgLength { min, max, found } => {
+                write_message(f, language, "invalid RG length, expected ", "tamanho de RG inválido, esperado ")?;
+                match (min == max, language) {
+                    (true, _) => write!(f, "{}", min)?,
+                    (false, Language::English) => write!(f, "{} to {}", min, max)?,
+                    (false, Language::BrazilianPortuguese) => write!(f, "{} a {}", min, max)?,
+                }
+                match language {
+                    Language::English => write!(f, " characters, found {}", found),
+                    Language::BrazilianPortuguese => write!(f, " caracteres, encontrado {}", found),
+                }
+            }
+            RgCreationError::MissingEmitterOrg => write_message(
+                f,
+                language,
+                "RG must be followed by its emitter organization",
+                "o RG deve ser seguido pelo órgão emissor",
+            ),
+            RgCreationError::InvalidCheckDigit { expected, found } => match language {
+                Language::English => write!(f, "invalid RG: check digit should be {}, found {}", expected, found),
+                Language::BrazilianPortuguese => {
+                    write!(f, "RG inválido: o dígito verificador deveria ser {}, encontrado {}", expected, found)
+                }
+            },
+        }
+    }
+}
+
+impl_error!(RgCreationError);
+
+/// Format rules of the Rgs emitted by an organization.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum RgRule {
+    /// Up to 8 digits followed by a modulo 11 check digit, which could be `X`.
+    Modulo11,
+    /// Only digits, optionally preceded by the `prefix` letters, without a check digit.
+    Digits { prefix: &'static str, min: usize, max: usize },
+    /// Letters and digits, used by emitters which do not publish their format.
+    Alphanumeric { min: usize, max: usize },
+}
+
+impl RgRule {
+    fn of(emitter_org: &EmitterOrg) -> RgRule {
+        match emitter_org {
+            EmitterOrg::SSP(UF::SP) => RgRule::Modulo11,
+            EmitterOrg::SSP(UF::RJ) => RgRule::Digits { prefix: "", min: 9, max: 9 },
+            // RJ Rgs are issued by DETRAN-RJ, and formerly by IFP, whose older Rgs have fewer
+            // digits, and MG Rgs by PC-MG.
+            EmitterOrg::DETRAN(UF::RJ) | EmitterOrg::IFP => RgRule::Digits { prefix: "", min: 6, max: 9 },
+            EmitterOrg::SSP(UF::MG) | EmitterOrg::PC(UF::MG) => RgRule::Digits { prefix: "MG", min: 6, max: 8 },
+            EmitterOrg::SSP(UF::RS) => RgRule::Digits { prefix: "", min: 10, max: 10 },
+            _ => RgRule::Alphanumeric { min: 4, max: 14 },
+        }
+    }
+}
+
+/// Whether `c` is a separator commonly used to format Rgs.
+fn is_separator(c: char) -> bool {
+    matches!(c, '.' | '-' | '/' | ' ')
+}
+
+/// Calculates the check digit of the 8 digits of a SSP-SP Rg, where `10` is represented as `X`.
+///
+/// The digits are multiplied by the weights 2 to 9, and the check digit is the one which makes
+/// the sum of the products plus the check digit multiplied by 100 a multiple of 11. Older Rgs,
+/// with less than 8 digits, must be padded with leading zeros.
+///
+/// # Example
+///
+/// ```
+/// use validbr::rg::calculate_sp_check_digit;
+///
+/// assert_eq!(calculate_sp_check_digit([2, 4, 6, 7, 8, 1, 3, 1]), '2');
+/// assert_eq!(calculate_sp_check_digit([1, 0, 0, 0, 0, 0, 0, 6]), 'X');
+/// assert_eq!(calculate_sp_check_digit([0, 1, 2, 3, 4, 5, 6, 7]), '2');
+/// ```
+pub const fn calculate_sp_check_digit(digits: [u8; 8]) -> char {
+    let mut sum = 0u32;
+    let mut i = 0;
+    while i < digits.len() {
+        sum += digits[i] as u32 * (i as u32 + 2);
+        i += 1;
+    }
+
+    match (11 - sum % 11) % 11 {
+        10 => 'X',
+        digit => (b'0' + digit as u8) as char,
+    }
+}
 
 impl Rg {
     /// Creates a new RG object
@@ -38,10 +173,469 @@ impl Rg {
             emitter_org
         }
     }
+
+    /// Parses a Rg `code` emitted by `emitter_org`, removing its separators (`.`, `-`, `/` and
+    /// spaces) and converting letters to uppercase, and validates the code through [`Rg::validate`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::Rg;
+    /// use validbr::UF::SP;
+    /// use validbr::EmitterOrg::SSP;
+    /// use validbr::rg::RgCreationError;
+    ///
+    /// let rg = Rg::parse_str("10.000.006-x", SSP(SP));
+    /// assert_eq!(rg, Ok(Rg::new("10000006X", SSP(SP))));
+    /// assert_eq!(
+    ///     Rg::parse_str("10.000.006-1", SSP(SP)),
+    ///     Err(RgCreationError::InvalidCheckDigit { expected: 'X', found: '1' })
+    /// );
+    /// ```
+    pub fn parse_str(code: &str, emitter_org: EmitterOrg) -> Result<Rg, RgCreationError> {
+        let rg = Rg {
+            code: code
+                .chars()
+                .filter(|c| !is_separator(*c))
+                .map(|c| c.to_ascii_uppercase())
+                .collect(),
+            emitter_org,
+        };
+
+        // Validates the provided code to report format errors at their positions in it.
+        rg.validate_code(code)?;
+        Ok(rg)
+    }
+
+    /// Parses a Rg string composed of the code followed by the emitter organization, such as
+    /// `12.345.678-9 SSP/SP`, and validates the code through [`Rg::parse_str`].
+    ///
+    /// The emitter organization is parsed through [`EmitterOrg::from_str`], starting at the first
+    /// word which, along with the following ones, is a known emitter organization. When there is
+    /// no known emitter organization, every word after the first one is parsed as the emitter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{Rg, EmitterOrg};
+    /// use validbr::UF::SP;
+    ///
+    /// let rg = Rg::new("246781312", EmitterOrg::SSP(SP));
+    /// assert_eq!(Rg::parse_full_str("24.678.131-2 SSP/SP"), Ok(rg));
+    /// assert_eq!(Rg::parse_full_str("246781312 SSPSP"), Rg::parse_full_str("24.678.131-2 SSP-SP"));
+    ///
+    /// let rg = Rg::new("1234567", EmitterOrg::PoliciaFederal);
+    /// assert_eq!(Rg::parse_full_str("1234567 Polícia Federal"), Ok(rg));
+    ///
+    /// let rg = Rg::parse_full_str("1234567 SESP-PR").unwrap();
+    /// assert_eq!(rg.emitter_org.to_string(), "SESPPR");
+    /// ```
+    pub fn parse_full_str(rg: &str) -> Result<Rg, RgCreationError> {
+        let (code, emitter_org) = split_emitter_org(rg).ok_or(RgCreationError::MissingEmitterOrg)?;
+        let offset = code.as_ptr() as usize - rg.as_ptr() as usize;
+
+        Rg::parse_str(code, emitter_org).map_err(|e| match e {
+            RgCreationError::InvalidRgStringFormat(mismatch) => {
+                RgCreationError::InvalidRgStringFormat(FormatMismatch {
+                    position: offset + mismatch.position,
+                    input_length: rg.len(),
+                    ..mismatch
+                })
+            }
+            e => e,
+        })
+    }
+
+    /// Validates the code of this Rg against the rules of its emitter organization.
+    ///
+    /// Rgs emitted by SSP-SP have up to 8 digits followed by a modulo 11 check digit, which could
+    /// be `X`, and older ones with less than 8 digits are checked as if padded with leading zeros.
+    /// SSP-RJ Rgs have 9 digits, DETRAN-RJ and IFP Rgs have 6 to 9 digits, as older ones are
+    /// shorter, SSP-RS Rgs have 10 digits, and SSP-MG and PC-MG Rgs have 6 to 8 digits, optionally
+    /// preceded by `MG`. The last digit of RJ Rgs is a check digit too, but neither RJ nor MG
+    /// emitters publish how their digits are calculated, so only their lengths are checked. Other
+    /// emitters do not publish their formats, so only 4 to 14 letters and digits are required.
+    /// Separators (`.`, `-`, `/` and spaces) are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{Rg, EmitterOrg};
+    /// use validbr::UF::{MG, RJ, SP};
+    /// use validbr::rg::RgCreationError;
+    ///
+    /// assert_eq!(Rg::new("24.678.131-2", EmitterOrg::SSP(SP)).validate(), Ok(()));
+    /// assert_eq!(Rg::new("1.234.567-2", EmitterOrg::SSP(SP)).validate(), Ok(()));
+    /// assert_eq!(Rg::new("MG-12.345.678", EmitterOrg::PC(MG)).validate(), Ok(()));
+    /// assert_eq!(Rg::new("A8974B-X", EmitterOrg::PoliciaFederal).validate(), Ok(()));
+    /// assert_eq!(
+    ///     Rg::new("12.345.678", EmitterOrg::SSP(RJ)).validate(),
+    ///     Err(RgCreationError::InvalidRgLength { min: 9, max: 9, found: 8 })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), RgCreationError> {
+        self.validate_code(&self.code)
+    }
+
+    fn validate_code(&self, code: &str) -> Result<(), RgCreationError> {
+        let mismatch = |position: usize, found: char| {
+            RgCreationError::InvalidRgStringFormat(FormatMismatch {
+                position,
+                found: Some(found),
+                input_length: code.len(),
+            })
+        };
+
+        let mut chars: Vec<(usize, char)> = code
+            .char_indices()
+            .filter(|(_, c)| !is_separator(*c))
+            .map(|(position, c)| (position, c.to_ascii_uppercase()))
+            .collect();
+
+        if let Some((position, c)) = chars.iter().find(|(_, c)| !c.is_ascii_alphanumeric()) {
+            return Err(mismatch(*position, *c))
+        }
+
+        let (min, max) = match RgRule::of(&self.emitter_org) {
+            RgRule::Modulo11 => (5, 9),
+            RgRule::Digits { prefix, min, max } => {
+                if chars.iter().map(|(_, c)| *c).take(prefix.len()).eq(prefix.chars()) {
+                    chars.drain(..prefix.len());
+                }
+                (min, max)
+            }
+            RgRule::Alphanumeric { min, max } => (min, max),
+        };
+
+        if chars.len() < min || chars.len() > max {
+            return Err(RgCreationError::InvalidRgLength { min, max, found: chars.len() })
+        }
+
+        match RgRule::of(&self.emitter_org) {
+            RgRule::Modulo11 => {
+                let (check, digits) = chars.split_last().expect("Length MUST be checked at this point");
+                // Older Rgs have fewer digits, which are padded with leading zeros.
+                let mut values = [0u8; 8];
+                for (value, (position, c)) in values[8 - digits.len()..].iter_mut().zip(digits) {
+                    *value = c.to_digit(10).ok_or_else(|| mismatch(*position, *c))? as u8;
+                }
+
+                let (position, found) = *check;
+                if !found.is_ascii_digit() && found != 'X' {
+                    return Err(mismatch(position, found))
+                }
+
+                let expected = calculate_sp_check_digit(values);
+                if expected != found {
+                    return Err(RgCreationError::InvalidCheckDigit { expected, found })
+                }
+            }
+            RgRule::Digits { .. } => {
+                if let Some((position, c)) = chars.iter().find(|(_, c)| !c.is_ascii_digit()) {
+                    return Err(mismatch(*position, *c))
+                }
+            }
+            RgRule::Alphanumeric { .. } => {}
+        }
+
+        Ok(())
+    }
 }
 
 impl fmt::Display for Rg {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{} {}", self.code, self.emitter_org)
     }
-}
\ No newline at end of file
+}
+
+/// Parses a Rg through [`Rg::parse_full_str`].
+///
+/// # Example
+///
+/// ```
+/// use validbr::{Rg, EmitterOrg};
+/// use validbr::UF::RJ;
+///
+/// assert_eq!("123456789 DETRAN/RJ".parse::<Rg>(), Ok(Rg::new("123456789", EmitterOrg::DETRAN(RJ))));
+/// ```
+impl FromStr for Rg {
+    type Err = RgCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rg::parse_full_str(s)
+    }
+}
+
+/// Splits a Rg string in its code and emitter organization, see [`Rg::parse_full_str`].
+pub(crate) fn split_emitter_org(rg: &str) -> Option<(&str, EmitterOrg)> {
+    let rg = rg.trim();
+    let known = rg
+        .char_indices()
+        .filter(|(_, c)| c.is_whitespace())
+        .find_map(|(i, _)| Some((rg[..i].trim_end(), EmitterOrg::parse_known(&rg[i..])?)));
+
+    known.or_else(|| {
+        let (code, emitter_org) = rg.split_once(char::is_whitespace)?;
+        Some((code, EmitterOrg::Other(emitter_org.trim().to_string())))
+    })
+}
+
+/// Professional councils which emit identity documents of their members, valid as Rgs.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Conselho {
+    /// Ordem dos Advogados do Brasil.
+    OAB,
+    /// Conselho Regional de Medicina.
+    CRM,
+    /// Conselho Regional de Engenharia e Agronomia.
+    CREA,
+    /// Conselho Regional de Contabilidade.
+    CRC,
+    /// Conselho Regional de Odontologia.
+    CRO,
+    /// Conselho Regional de Enfermagem.
+    COREN,
+    /// Conselho Regional de Farmácia.
+    CRF,
+    /// Conselho Regional de Psicologia.
+    CRP,
+    /// Conselho Regional de Medicina Veterinária.
+    CRMV,
+    /// Conselho Regional de Administração.
+    CRA,
+    /// Conselho de Arquitetura e Urbanismo.
+    CAU,
+}
+
+impl Conselho {
+    /// All the professional councils.
+    pub const ALL: [Conselho; 11] = [
+        Conselho::OAB, Conselho::CRM, Conselho::CREA, Conselho::CRC, Conselho::CRO, Conselho::COREN,
+        Conselho::CRF, Conselho::CRP, Conselho::CRMV, Conselho::CRA, Conselho::CAU,
+    ];
+
+    /// Official abbreviation of the council, for example: `OAB`.
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Conselho::OAB => "OAB",
+            Conselho::CRM => "CRM",
+            Conselho::CREA => "CREA",
+            Conselho::CRC => "CRC",
+            Conselho::CRO => "CRO",
+            Conselho::COREN => "COREN",
+            Conselho::CRF => "CRF",
+            Conselho::CRP => "CRP",
+            Conselho::CRMV => "CRMV",
+            Conselho::CRA => "CRA",
+            Conselho::CAU => "CAU",
+        }
+    }
+
+    /// Full name of the council, for example: `Ordem dos Advogados do Brasil`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Conselho::OAB => "Ordem dos Advogados do Brasil",
+            Conselho::CRM => "Conselho Regional de Medicina",
+            Conselho::CREA => "Conselho Regional de Engenharia e Agronomia",
+            Conselho::CRC => "Conselho Regional de Contabilidade",
+            Conselho::CRO => "Conselho Regional de Odontologia",
+            Conselho::COREN => "Conselho Regional de Enfermagem",
+            Conselho::CRF => "Conselho Regional de Farmácia",
+            Conselho::CRP => "Conselho Regional de Psicologia",
+            Conselho::CRMV => "Conselho Regional de Medicina Veterinária",
+            Conselho::CRA => "Conselho Regional de Administração",
+            Conselho::CAU => "Conselho de Arquitetura e Urbanismo",
+        }
+    }
+}
+
+/// Emitter organizations which are present in every UF.
+const STATE_EMITTERS: [fn(UF) -> EmitterOrg; 7] = [
+    EmitterOrg::SSP,
+    EmitterOrg::SESP,
+    EmitterOrg::SDS,
+    EmitterOrg::SEJUSP,
+    EmitterOrg::PC,
+    EmitterOrg::IIPC,
+    EmitterOrg::DETRAN,
+];
+
+/// Emitter organizations which are not bound to an UF, along with their alternative names.
+const NATIONAL_EMITTERS: [(EmitterOrg, &[&str]); 8] = [
+    (EmitterOrg::IFP, &[]),
+    (EmitterOrg::PoliciaFederal, &["PF", "Departamento de Polícia Federal"]),
+    (EmitterOrg::CartorioCivil, &["Cartório", "Cartório Civil", "Registro Civil"]),
+    (EmitterOrg::Exercito, &["Exército", "Comando do Exército"]),
+    (EmitterOrg::Marinha, &["Marinha", "Comando da Marinha"]),
+    (EmitterOrg::Aeronautica, &["FAB", "Aeronáutica", "Força Aérea Brasileira"]),
+    (EmitterOrg::DPMAF, &[]),
+    (EmitterOrg::CGPI, &[]),
+];
+
+/// Removes the separators of `s`, which are ignored when parsing emitter organizations.
+fn compact(s: &str) -> String {
+    s.chars().filter(|c| !matches!(c, '/' | '-' | '.' | ',') && !c.is_whitespace()).collect()
+}
+
+/// Whether the `compact` string is either the abbreviation or the full name of `emitter_org`.
+fn is_named(compact: &str, emitter_org: &EmitterOrg) -> bool {
+    eq_ignore_accents(compact, emitter_org.abbreviation()) || eq_ignore_accents(compact, &self::compact(emitter_org.name()))
+}
+
+impl EmitterOrg {
+    /// Former, misspelled, name of [`EmitterOrg::PoliciaFederal`].
+    #[deprecated(note = "use `EmitterOrg::PoliciaFederal` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const PoliciaFedaral: EmitterOrg = EmitterOrg::PoliciaFederal;
+
+    /// Official abbreviation of the organization, without the UF, for example: `SSP`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::EmitterOrg;
+    /// use validbr::UF::SP;
+    /// use validbr::rg::Conselho;
+    ///
+    /// assert_eq!(EmitterOrg::SSP(SP).abbreviation(), "SSP");
+    /// assert_eq!(EmitterOrg::Conselho(Conselho::OAB, SP).abbreviation(), "OAB");
+    /// assert_eq!(EmitterOrg::Exercito.abbreviation(), "EB");
+    /// ```
+    pub fn abbreviation(&self) -> &str {
+        match self {
+            EmitterOrg::SSP(_) => "SSP",
+            EmitterOrg::SESP(_) => "SESP",
+            EmitterOrg::SDS(_) => "SDS",
+            EmitterOrg::SEJUSP(_) => "SEJUSP",
+            EmitterOrg::PC(_) => "PC",
+            EmitterOrg::IIPC(_) => "IIPC",
+            EmitterOrg::DETRAN(_) => "DETRAN",
+            EmitterOrg::IFP => "IFP",
+            EmitterOrg::PoliciaFederal => "DPF",
+            EmitterOrg::CartorioCivil => "RCPN",
+            EmitterOrg::Exercito => "EB",
+            EmitterOrg::Marinha => "MB",
+            EmitterOrg::Aeronautica => "COMAER",
+            EmitterOrg::Conselho(conselho, _) => conselho.abbreviation(),
+            EmitterOrg::DPMAF => "DPMAF",
+            EmitterOrg::CGPI => "CGPI",
+            EmitterOrg::Other(other) => other,
+        }
+    }
+
+    /// Full name of the organization, without the UF, for example: `Secretaria de Segurança Pública`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::EmitterOrg;
+    /// use validbr::UF::PR;
+    ///
+    /// assert_eq!(EmitterOrg::SESP(PR).name(), "Secretaria de Estado da Segurança Pública");
+    /// assert_eq!(EmitterOrg::Marinha.name(), "Marinha do Brasil");
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            EmitterOrg::SSP(_) => "Secretaria de Segurança Pública",
+            EmitterOrg::SESP(_) => "Secretaria de Estado da Segurança Pública",
+            EmitterOrg::SDS(_) => "Secretaria de Defesa Social",
+            EmitterOrg::SEJUSP(_) => "Secretaria de Estado de Justiça e Segurança Pública",
+            EmitterOrg::PC(_) => "Polícia Civil",
+            EmitterOrg::IIPC(_) => "Instituto de Identificação da Polícia Civil",
+            EmitterOrg::DETRAN(_) => "Departamento Estadual de Trânsito",
+            EmitterOrg::IFP => "Instituto Félix Pacheco",
+            EmitterOrg::PoliciaFederal => "Polícia Federal",
+            EmitterOrg::CartorioCivil => "Cartório de Registro Civil das Pessoas Naturais",
+            EmitterOrg::Exercito => "Exército Brasileiro",
+            EmitterOrg::Marinha => "Marinha do Brasil",
+            EmitterOrg::Aeronautica => "Comando da Aeronáutica",
+            EmitterOrg::Conselho(conselho, _) => conselho.name(),
+            EmitterOrg::DPMAF => "Divisão de Polícia Marítima, Aeroportuária e de Fronteiras",
+            EmitterOrg::CGPI => "Coordenação-Geral de Polícia de Imigração",
+            EmitterOrg::Other(other) => other,
+        }
+    }
+
+    /// UF of the organization, if it is bound to one.
+    pub fn uf(&self) -> Option<UF> {
+        match self {
+            EmitterOrg::SSP(uf)
+            | EmitterOrg::SESP(uf)
+            | EmitterOrg::SDS(uf)
+            | EmitterOrg::SEJUSP(uf)
+            | EmitterOrg::PC(uf)
+            | EmitterOrg::IIPC(uf)
+            | EmitterOrg::DETRAN(uf)
+            | EmitterOrg::Conselho(_, uf) => Some(*uf),
+            EmitterOrg::IFP => Some(UF::RJ),
+            _ => None,
+        }
+    }
+
+    /// Parses `s` if it is a known emitter organization.
+    fn parse_known(s: &str) -> Option<EmitterOrg> {
+        let compact = compact(s);
+
+        // Organizations bound to an UF end with the two letters abbreviation of the UF.
+        let split = compact
+            .char_indices()
+            .rev()
+            .nth(1)
+            .map(|(i, _)| compact.split_at(i))
+            .filter(|(_, uf)| uf.is_ascii())
+            .and_then(|(head, uf)| Some((head, uf.parse::<UF>().ok()?)));
+
+        if let Some((head, uf)) = split {
+            let found = STATE_EMITTERS
+                .iter()
+                .map(|emitter_org| emitter_org(uf))
+                .chain(Conselho::ALL.iter().map(|conselho| EmitterOrg::Conselho(*conselho, uf)))
+                .find(|emitter_org| is_named(head, emitter_org));
+
+            if found.is_some() {
+                return found
+            }
+        }
+
+        NATIONAL_EMITTERS
+            .iter()
+            .find(|(emitter_org, aliases)| {
+                is_named(&compact, emitter_org)
+                    || aliases.iter().any(|alias| eq_ignore_accents(&compact, &self::compact(alias)))
+                    || matches!(split, Some((head, uf)) if emitter_org.uf() == Some(uf) && is_named(head, emitter_org))
+            })
+            .map(|(emitter_org, _)| emitter_org.clone())
+    }
+}
+
+/// Parses an [`EmitterOrg`] from either its abbreviation or its full name, ignoring case, accents
+/// and the `/`, `-`, `.`, `,` and space separators, thus round-tripping with both forms of its
+/// [`Display`](fmt::Display) representation.
+///
+/// Organizations bound to an UF are followed by the UF abbreviation, for example: `SSP/SP`,
+/// `SESP-PR`, `DETRAN RJ`, `OAB/SP` or `SSPSP`. Some organizations are also known by other
+/// names, such as `PF` for the Polícia Federal and `FAB` for the Aeronáutica. Any other
+/// organization is parsed as [`EmitterOrg::Other`] holding the trimmed string.
+///
+/// # Example
+///
+/// ```
+/// use validbr::EmitterOrg;
+/// use validbr::UF::SP;
+///
+/// assert_eq!("SSPSP".parse::<EmitterOrg>(), Ok(EmitterOrg::SSP(SP)));
+/// assert_eq!("ssp/sp".parse::<EmitterOrg>(), Ok(EmitterOrg::SSP(SP)));
+/// assert_eq!("Polícia Federal".parse::<EmitterOrg>(), Ok(EmitterOrg::PoliciaFederal));
+/// assert_eq!("DPF".parse::<EmitterOrg>(), Ok(EmitterOrg::PoliciaFederal));
+/// assert_eq!("DETRAN-SP".parse::<EmitterOrg>(), Ok(EmitterOrg::DETRAN(SP)));
+/// assert_eq!("Secretaria de Segurança Pública - SP".parse::<EmitterOrg>(), Ok(EmitterOrg::SSP(SP)));
+/// assert_eq!("Exército".parse::<EmitterOrg>(), Ok(EmitterOrg::Exercito));
+/// assert_eq!("DETRAN".parse::<EmitterOrg>(), Ok(EmitterOrg::Other("DETRAN".to_string())));
+/// ```
+impl FromStr for EmitterOrg {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(EmitterOrg::parse_known(s).unwrap_or_else(|| EmitterOrg::Other(s.trim().to_string())))
+    }
+}
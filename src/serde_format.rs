@@ -0,0 +1,578 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # Serde support
+//!
+//! [`Cpf`] and [`Cnpj`] are serialized as their formatted string representation, such as
+//! `"123.456.789-09"` and `"12.345.678/0001-95"`, and deserialized through `parse_str`, thus
+//! invalid documents are rejected. Both the formatted and the only digits representations are
+//! accepted when deserializing.
+//!
+//! The representation could be selected per field with the [`formatted`](crate::serde_format::formatted) and [`digits_only`](crate::serde_format::digits_only)
+//! modules:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use validbr::{Cnpj, Cpf};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Company {
+//!     #[serde(with = "validbr::serde_format::digits_only")]
+//!     cnpj: Cnpj,
+//!     #[serde(with = "validbr::serde_format::formatted")]
+//!     owner: Cpf,
+//! }
+//!
+//! let json = r#"{"cnpj":"12345678000195","owner":"123.456.789-09"}"#;
+//! let company: Company = serde_json::from_str(json).unwrap();
+//! assert_eq!(serde_json::to_string(&company).unwrap(), json);
+//!
+//! let invalid = r#"{"cnpj":"12345678000196","owner":"123.456.789-09"}"#;
+//! assert!(serde_json::from_str::<Company>(invalid).is_err());
+//! ```
+//!
+//! [`Cep`] is represented as `"01310-100"` or `"01310100"`, [`Pis`] as `"120.12345.67-2"` or
+//! `"12012345672"`, [`Cns`] as `"898 0010 0000 9310"` or `"898001000009310"`, and [`TituloEleitor`]
+//! as `"1023 8501 0671"` or `"102385010671"`, in the same way as Cpf and Cnpj.
+//! [`Renavam`] is represented by its 11 digits in both modules, legacy Renavam with 9 digits are
+//! accepted in deserialization. [`Placa`] is represented as `"ABC-1234"` or `"ABC1234"` in the old
+//! format and as `"ABC1C34"` in the Mercosul format.
+//! [`Cnh`] is represented by its 11 digits in both modules, [`CnhCategory`](crate::cnh::CnhCategory) by its letters and
+//! [`Date`](crate::cnh::Date) in the `YYYY-MM-DD` format.
+//!
+//! [`UF`] is represented by its abbreviation, such as `"SP"`, and, with `alloc` feature,
+//! `Rg` and `EmitterOrg` are represented by their [`Display`](core::fmt::Display) string,
+//! such as `"A8974B-X SSPSP"`. Rgs are deserialized through [`Rg::parse_full_str`], thus
+//! invalid Rgs are rejected, and the [`rg_unvalidated`](crate::serde_format::rg_unvalidated)
+//! module keeps Rgs created with [`Rg::new`] as they were serialized.
+use crate::{Cep, Cnh, Cnpj, Cns, Cpf, Pis, Placa, Renavam, TituloEleitor, UF};
+use crate::cnh::{CnhCategory, Date};
+use core::fmt;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+#[cfg(feature = "alloc")]
+use crate::{EmitterOrg, Rg};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::Cpf {}
+    impl Sealed for crate::Cnpj {}
+    impl Sealed for crate::Cnh {}
+    impl Sealed for crate::Cep {}
+    impl Sealed for crate::Pis {}
+    impl Sealed for crate::Cns {}
+    impl Sealed for crate::Renavam {}
+    impl Sealed for crate::Placa {}
+    impl Sealed for crate::TituloEleitor {}
+}
+
+/// Documents which could be serialized through [`formatted`] and [`digits_only`] modules.
+///
+/// This trait is sealed and implemented only for [`Cpf`], [`Cnpj`], [`Cnh`], [`Cep`], [`Pis`],
+/// [`Cns`], [`Renavam`], [`Placa`] and [`TituloEleitor`].
+pub trait Document: private::Sealed + fmt::Display + Sized {
+    /// Name of the document, used in deserialization error messages.
+    #[doc(hidden)]
+    const NAME: &'static str;
+    /// Error returned by `parse_str` of the document.
+    #[doc(hidden)]
+    type Error: fmt::Display;
+
+    /// Parses the document in any format accepted by its `parse_str`.
+    #[doc(hidden)]
+    fn parse_document(s: &str) -> Result<Self, Self::Error>;
+
+    /// Writes the document without any separator.
+    #[doc(hidden)]
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+impl Document for Cpf {
+    const NAME: &'static str = "CPF";
+    type Error = crate::cpf::CpfCreationError;
+
+    fn parse_document(s: &str) -> Result<Self, Self::Error> {
+        Cpf::parse_str(s)
+    }
+
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for digit in self.digits.iter().chain(self.verifier_digits.iter()) {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+impl Document for Cnpj {
+    const NAME: &'static str = "CNPJ";
+    type Error = crate::cnpj::CnpjCreationError;
+
+    fn parse_document(s: &str) -> Result<Self, Self::Error> {
+        Cnpj::parse_str(s)
+    }
+
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        crate::cnpj::write_values(f, &self.digits)?;
+        crate::cnpj::write_values(f, &self.branch_digits)?;
+        crate::cnpj::write_values(f, &self.verifier_digits)
+    }
+}
+
+impl Document for Cnh {
+    const NAME: &'static str = "CNH";
+    type Error = crate::cnh::CnhCreationError;
+
+    fn parse_document(s: &str) -> Result<Self, Self::Error> {
+        Cnh::parse_str(s)
+    }
+
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Document for Cep {
+    const NAME: &'static str = "CEP";
+    type Error = crate::cep::CepCreationError;
+
+    fn parse_document(s: &str) -> Result<Self, Self::Error> {
+        Cep::parse_str(s)
+    }
+
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for digit in self.digits.iter() {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+impl Document for Pis {
+    const NAME: &'static str = "PIS";
+    type Error = crate::pis::PisCreationError;
+
+    fn parse_document(s: &str) -> Result<Self, Self::Error> {
+        Pis::parse_str(s)
+    }
+
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for digit in self.digits.iter() {
+            write!(f, "{}", digit)?;
+        }
+        write!(f, "{}", self.verifier_digit)
+    }
+}
+
+impl Document for Cns {
+    const NAME: &'static str = "CNS";
+    type Error = crate::cns::CnsCreationError;
+
+    fn parse_document(s: &str) -> Result<Self, Self::Error> {
+        Cns::parse_str(s)
+    }
+
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for digit in self.digits.iter() {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+impl Document for Renavam {
+    const NAME: &'static str = "RENAVAM";
+    type Error = crate::renavam::RenavamCreationError;
+
+    fn parse_document(s: &str) -> Result<Self, Self::Error> {
+        Renavam::parse_str(s)
+    }
+
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Document for Placa {
+    const NAME: &'static str = "placa";
+    type Error = crate::placa::PlacaCreationError;
+
+    fn parse_document(s: &str) -> Result<Self, Self::Error> {
+        Placa::parse_str(s)
+    }
+
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for c in self.characters.iter() {
+            write!(f, "{}", *c as char)?;
+        }
+        Ok(())
+    }
+}
+
+impl Document for TituloEleitor {
+    const NAME: &'static str = "título de eleitor";
+    type Error = crate::titulo_eleitor::TituloEleitorCreationError;
+
+    fn parse_document(s: &str) -> Result<Self, Self::Error> {
+        TituloEleitor::parse_str(s)
+    }
+
+    fn fmt_digits_only(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let digits = self.digits.iter().chain(self.domicilio_digits.iter()).chain(self.verifier_digits.iter());
+        for digit in digits {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays a [`Document`] without any separator.
+struct DigitsOnly<'a, T>(&'a T);
+
+impl<T: Document> fmt::Display for DigitsOnly<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_digits_only(f)
+    }
+}
+
+/// Deserializes a [`Document`] from a string, rejecting invalid documents.
+struct DocumentVisitor<T>(PhantomData<T>);
+
+impl<T: Document> Visitor<'_> for DocumentVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a {} string", T::NAME)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        T::parse_document(v).map_err(E::custom)
+    }
+}
+
+/// Serializes documents as their formatted representation, such as `"123.456.789-09"`, which is
+/// the default representation of [`Cpf`] and [`Cnpj`].
+///
+/// Deserialization accepts any format accepted by `parse_str`.
+pub mod formatted {
+    use super::{Document, DocumentVisitor};
+    use core::marker::PhantomData;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes `document` as its formatted representation.
+    pub fn serialize<T: Document, S: Serializer>(document: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(document)
+    }
+
+    /// Deserializes a document from any format accepted by its `parse_str`.
+    pub fn deserialize<'de, T: Document, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(DocumentVisitor(PhantomData))
+    }
+}
+
+/// Serializes documents as their only digits representation, such as `"12345678909"`.
+///
+/// Deserialization accepts any format accepted by `parse_str`.
+pub mod digits_only {
+    use super::{DigitsOnly, Document};
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes `document` as its only digits representation.
+    pub fn serialize<T: Document, S: Serializer>(document: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&DigitsOnly(document))
+    }
+
+    /// Deserializes a document from any format accepted by its `parse_str`.
+    pub fn deserialize<'de, T: Document, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::formatted::deserialize(deserializer)
+    }
+}
+
+impl Serialize for Cpf {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        formatted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cpf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        formatted::deserialize(deserializer)
+    }
+}
+
+impl Serialize for Cnpj {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        formatted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cnpj {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        formatted::deserialize(deserializer)
+    }
+}
+
+impl Serialize for Cnh {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        formatted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cnh {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        formatted::deserialize(deserializer)
+    }
+}
+
+impl Serialize for Cep {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        formatted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cep {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        formatted::deserialize(deserializer)
+    }
+}
+
+impl Serialize for Pis {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        formatted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pis {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        formatted::deserialize(deserializer)
+    }
+}
+
+impl Serialize for Cns {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        formatted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cns {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        formatted::deserialize(deserializer)
+    }
+}
+
+impl Serialize for Renavam {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        formatted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Renavam {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        formatted::deserialize(deserializer)
+    }
+}
+
+impl Serialize for Placa {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        formatted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Placa {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        formatted::deserialize(deserializer)
+    }
+}
+
+impl Serialize for TituloEleitor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        formatted::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TituloEleitor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        formatted::deserialize(deserializer)
+    }
+}
+
+/// Deserializes any type from a string through `parse`, reporting its error when it fails.
+struct FromStrVisitor<T, Err> {
+    expecting: &'static str,
+    parse: fn(&str) -> Result<T, Err>,
+}
+
+impl<T, Err: fmt::Display> Visitor<'_> for FromStrVisitor<T, Err> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        (self.parse)(v).map_err(E::custom)
+    }
+}
+
+impl Serialize for CnhCategory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CnhCategory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor {
+            expecting: "a CNH category",
+            parse: str::parse,
+        })
+    }
+}
+
+/// Displays a [`Date`] in the `YYYY-MM-DD` format.
+struct IsoDate<'a>(&'a Date);
+
+impl fmt::Display for IsoDate<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.0.year(), self.0.month(), self.0.day())
+    }
+}
+
+/// Serializes [`Date`] in the `YYYY-MM-DD` format, both `YYYY-MM-DD` and `DD/MM/YYYY` formats
+/// are accepted when deserializing.
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&IsoDate(self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor {
+            expecting: "a date",
+            parse: str::parse,
+        })
+    }
+}
+
+impl Serialize for UF {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UF {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor {
+            expecting: "an UF abbreviation",
+            parse: str::parse,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Serialize for EmitterOrg {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for EmitterOrg {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor {
+            expecting: "an emitter organization",
+            parse: str::parse,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Serialize for Rg {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for Rg {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor {
+            expecting: "a RG code followed by its emitter organization",
+            parse: Rg::parse_full_str,
+        })
+    }
+}
+
+/// Serializes [`Rg`]s as their [`Display`](core::fmt::Display) string, such as `"A8974B-X SSPSP"`,
+/// and deserializes them without validating nor normalizing their codes.
+///
+/// Rgs created with [`Rg::new`] are not validated, thus they are not always deserialized by
+/// the default implementation, which parses them through [`Rg::parse_full_str`]. This module
+/// keeps the code as serialized, so every Rg is deserialized back to an equal value:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use validbr::{EmitterOrg, Rg};
+/// use validbr::UF::SP;
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Person {
+///     #[serde(with = "validbr::serde_format::rg_unvalidated")]
+///     rg: Rg,
+/// }
+///
+/// let person = Person { rg: Rg::new("A8974B-X", EmitterOrg::SSP(SP)) };
+/// let json = serde_json::to_string(&person).unwrap();
+/// assert_eq!(json, r#"{"rg":"A8974B-X SSPSP"}"#);
+/// assert_eq!(serde_json::from_str::<Person>(&json).unwrap(), person);
+/// ```
+#[cfg(feature = "alloc")]
+pub mod rg_unvalidated {
+    use super::FromStrVisitor;
+    use crate::Rg;
+    use crate::rg::{split_emitter_org, RgCreationError};
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes `rg` as its [`Display`](core::fmt::Display) string.
+    pub fn serialize<S: Serializer>(rg: &Rg, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(rg)
+    }
+
+    /// Deserializes a Rg code followed by its emitter organization, keeping the code as given.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rg, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor {
+            expecting: "a RG code followed by its emitter organization",
+            parse: parse_unvalidated,
+        })
+    }
+
+    fn parse_unvalidated(rg: &str) -> Result<Rg, RgCreationError> {
+        let (code, emitter_org) = split_emitter_org(rg).ok_or(RgCreationError::MissingEmitterOrg)?;
+        Ok(Rg::new(code, emitter_org))
+    }
+}
@@ -0,0 +1,449 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+//! # Título de Eleitor
+//!
+//! This module provides utility for constructing and validating Títulos de Eleitor (voter
+//! registration numbers). If a Título de Eleitor was successfully constructed with
+//! [`TituloEleitor::new`] or [`TituloEleitor::parse_str`] it means that it is valid.
+//!
+//! A Título de Eleitor consists of 8 sequential digits, 2 digits identifying the [`Domicilio`](crate::titulo_eleitor::Domicilio)
+//! (the UF where the voter is registered, or `28` for voters living abroad) and 2 verifier digits.
+use crate::{TituloEleitor, UF};
+use crate::parse::{self, FormatError, ParseOptions};
+use crate::error::{impl_error, write_message, write_with_details, FormatMismatch, Language, LocalizedError, VerifierDigitsMismatch};
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Formatter;
+use core::str::FromStr;
+#[cfg(feature = "rand")]
+use {
+    rand::distributions::{Distribution, Standard, Uniform},
+    rand::Rng,
+};
+
+/// Mask of the Título de Eleitor format, see [`parse::normalize`].
+const TITULO_ELEITOR_MASK: &str = "#### #### ####";
+
+/// Formats the Título de Eleitor as `#### #### ####`, which is how it is printed in the voter card.
+///
+/// # Example
+///
+/// ```
+/// use validbr::TituloEleitor;
+/// let titulo = TituloEleitor::parse_str("102385010671").expect("Invalid título de eleitor.");
+/// assert_eq!(format!("{}", titulo), "1023 8501 0671")
+/// ```
+impl fmt::Display for TituloEleitor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let digits = self.digits.iter().chain(self.domicilio_digits.iter()).chain(self.verifier_digits.iter());
+        for (i, digit) in digits.enumerate() {
+            if i > 0 && i % 4 == 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum TituloEleitorCreationError {
+    /// When provided Título de Eleitor digits could not be validated against its verifier digits,
+    /// in other words, when provided Título de Eleitor is not valid.
+    ///
+    /// Holds the expected and the provided verifier digits.
+    InvalidTituloEleitorDigits(VerifierDigitsMismatch<2>),
+    /// When provided Título de Eleitor string is not a valid Título de Eleitor format.
+    ///
+    /// Supported Título de Eleitor formats are:
+    /// - 0000 0000 0000
+    /// - 000000000000
+    ///
+    /// Holds the position of the character which does not match the format.
+    InvalidTituloEleitorStringFormat(FormatMismatch),
+    /// When provided Título de Eleitor string has less digits than a Título de Eleitor.
+    ShortTituloEleitorString(FormatMismatch),
+    /// When provided numbers for digits are out of bounds, in other words, they are not
+    /// respecting the range of `0..=9`.
+    DigitsOutOfBounds,
+    /// When the domicílio digits are not the code of an UF nor `28` (exterior).
+    ///
+    /// Holds the provided code.
+    InvalidDomicilio(u8),
+}
+
+impl LocalizedError for TituloEleitorCreationError {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match self {
+            TituloEleitorCreationError::InvalidTituloEleitorDigits(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid título de eleitor",
+                "título de eleitor inválido",
+                mismatch,
+            ),
+            TituloEleitorCreationError::InvalidTituloEleitorStringFormat(mismatch) => write_with_details(
+                f,
+                language,
+                "invalid título de eleitor format, expected #### #### #### or ############",
+                "formato de título de eleitor inválido, esperado #### #### #### ou ############",
+                mismatch,
+            ),
+            TituloEleitorCreationError::ShortTituloEleitorString(mismatch) => write_with_details(
+                f,
+                language,
+                "título de eleitor has less than 12 digits",
+                "título de eleitor possui menos de 12 dígitos",
+                mismatch,
+            ),
+            TituloEleitorCreationError::DigitsOutOfBounds => write_message(
+                f,
+                language,
+                "título de eleitor digits must be in the range of 0 to 9",
+                "os dígitos do título de eleitor devem estar entre 0 e 9",
+            ),
+            TituloEleitorCreationError::InvalidDomicilio(code) => match language {
+                Language::English => write!(f, "invalid título de eleitor: {:02} is not the code of an UF", code),
+                Language::BrazilianPortuguese => {
+                    write!(f, "título de eleitor inválido: {:02} não é o código de uma UF", code)
+                }
+            },
+        }
+    }
+}
+
+impl_error!(TituloEleitorCreationError);
+
+/// Where the voter is registered: an UF or abroad (exterior).
+///
+/// Títulos de Eleitor identify the domicílio with codes assigned by TSE, which differ from the
+/// IBGE codes of the UFs, for example, São Paulo is `01`.
+///
+/// # Example
+///
+/// ```
+/// use validbr::{TituloEleitor, UF};
+/// use validbr::titulo_eleitor::Domicilio;
+///
+/// let titulo = TituloEleitor::parse_str("1023 8501 0671").unwrap();
+/// assert_eq!(titulo.domicilio(), Domicilio::Uf(UF::PR));
+/// assert_eq!(Domicilio::from_code(1), Some(Domicilio::Uf(UF::SP)));
+/// assert_eq!(Domicilio::Exterior.code(), 28);
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Domicilio {
+    /// Voter registered in an UF.
+    Uf(UF),
+    /// Voter registered abroad.
+    Exterior,
+}
+
+/// UFs sorted by their TSE code, starting at `01`.
+const TSE_UFS: [UF; 27] = [
+    UF::SP, UF::MG, UF::RJ, UF::RS, UF::BA, UF::PR, UF::CE, UF::PE, UF::SC,
+    UF::GO, UF::MA, UF::PB, UF::PA, UF::ES, UF::PI, UF::RN, UF::AL, UF::MT,
+    UF::MS, UF::DF, UF::SE, UF::AM, UF::RO, UF::AC, UF::AP, UF::RR, UF::TO,
+];
+
+/// TSE code of the voters registered abroad.
+const EXTERIOR_CODE: u8 = 28;
+
+impl Domicilio {
+    /// Resolves the Domicilio of its TSE `code`, from `1` to `28`.
+    pub fn from_code(code: u8) -> Option<Domicilio> {
+        match code {
+            EXTERIOR_CODE => Some(Domicilio::Exterior),
+            code => TSE_UFS.get(usize::from(code).checked_sub(1)?).map(|uf| Domicilio::Uf(*uf)),
+        }
+    }
+
+    /// TSE code of this Domicilio, from `1` to `28`.
+    pub fn code(&self) -> u8 {
+        match self {
+            Domicilio::Uf(uf) => TSE_UFS.iter().position(|other| other == uf).expect("Every UF MUST have a TSE code") as u8 + 1,
+            Domicilio::Exterior => EXTERIOR_CODE,
+        }
+    }
+
+    /// UF of this Domicilio, or [`None`] if the voter is registered abroad.
+    pub fn uf(&self) -> Option<UF> {
+        match self {
+            Domicilio::Uf(uf) => Some(*uf),
+            Domicilio::Exterior => None,
+        }
+    }
+}
+
+impl fmt::Display for Domicilio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Domicilio::Uf(uf) => write!(f, "{}", uf),
+            Domicilio::Exterior => f.write_str("ZZ"),
+        }
+    }
+}
+
+impl TituloEleitor {
+    /// Creates a new TituloEleitor if the provided `[digits]`, `[domicilio_digits]` and
+    /// `[verifier_digits]` are valid.
+    ///
+    /// # Example
+    /// ```
+    /// use validbr::TituloEleitor;
+    /// use validbr::titulo_eleitor::TituloEleitorCreationError;
+    /// use validbr::error::VerifierDigitsMismatch;
+    ///
+    /// let titulo = TituloEleitor::new([1, 0, 2, 3, 8, 5, 0, 1], [0, 6], [7, 1]); // Valid título
+    /// assert!(titulo.is_ok());
+    ///
+    /// let titulo = TituloEleitor::new([1, 0, 2, 3, 8, 5, 0, 1], [0, 6], [7, 2]); // Invalid título
+    /// assert_eq!(titulo, Err(TituloEleitorCreationError::InvalidTituloEleitorDigits(VerifierDigitsMismatch { expected: [7, 1], found: [7, 2] })));
+    /// ```
+    pub fn new(
+        digits: [u8; 8],
+        domicilio_digits: [u8; 2],
+        verifier_digits: [u8; 2],
+    ) -> Result<TituloEleitor, TituloEleitorCreationError> {
+        let mut all_digits = digits.iter().chain(domicilio_digits.iter()).chain(verifier_digits.iter());
+        if !all_digits.all(|d| *d <= 9) {
+            return Err(TituloEleitorCreationError::DigitsOutOfBounds)
+        }
+
+        let code = domicilio_digits[0] * 10 + domicilio_digits[1];
+        if Domicilio::from_code(code).is_none() {
+            return Err(TituloEleitorCreationError::InvalidDomicilio(code))
+        }
+
+        let (first, second) = calculate_verifier_digits(digits, domicilio_digits);
+        if [first, second] == verifier_digits {
+            Ok(TituloEleitor {
+                digits,
+                domicilio_digits,
+                verifier_digits,
+            })
+        } else {
+            Err(TituloEleitorCreationError::InvalidTituloEleitorDigits(VerifierDigitsMismatch {
+                expected: [first, second],
+                found: verifier_digits,
+            }))
+        }
+    }
+
+    /// Parses a Título de Eleitor String to a [`TituloEleitor`].
+    ///
+    /// Supported formats are:
+    /// - `#### #### ####` (Printed in the voter card)
+    /// - `############` (Only digits)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::TituloEleitor;
+    /// let titulo = TituloEleitor::parse_str("1023 8501 0671");
+    /// assert_eq!(titulo, Ok(TituloEleitor { digits: [1, 0, 2, 3, 8, 5, 0, 1], domicilio_digits: [0, 6], verifier_digits: [7, 1] }));
+    /// assert_eq!(TituloEleitor::parse_str("102385010671"), titulo);
+    /// ```
+    pub fn parse_str(titulo: &str) -> Result<TituloEleitor, TituloEleitorCreationError> {
+        TituloEleitor::parse_str_with(titulo, &ParseOptions::strict())
+    }
+
+    /// Parses a Título de Eleitor String to a [`TituloEleitor`] according to the provided parse
+    /// `options`.
+    ///
+    /// See [`ParseOptions`] for the tolerated formatting noise of each option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::TituloEleitor;
+    /// use validbr::parse::ParseOptions;
+    ///
+    /// let titulo = TituloEleitor::parse_str_with(" 1023.8501.0671 ", &ParseOptions::lenient());
+    /// assert_eq!(titulo, TituloEleitor::parse_str("102385010671"));
+    /// ```
+    pub fn parse_str_with(
+        titulo: &str,
+        options: &ParseOptions,
+    ) -> Result<TituloEleitor, TituloEleitorCreationError> {
+        let normalized: [u8; 12] = parse::normalize(titulo, options, TITULO_ELEITOR_MASK)
+            .map_err(|e| match e {
+                FormatError::InvalidFormat(mismatch) => TituloEleitorCreationError::InvalidTituloEleitorStringFormat(mismatch),
+                FormatError::Short(mismatch) => TituloEleitorCreationError::ShortTituloEleitorString(mismatch),
+            })?;
+
+        let digits: [u8; 8] = core::array::from_fn(|i| normalized[i] - b'0');
+        let domicilio_digits: [u8; 2] = core::array::from_fn(|i| normalized[8 + i] - b'0');
+        let verifier_digits: [u8; 2] = core::array::from_fn(|i| normalized[10 + i] - b'0');
+
+        TituloEleitor::new(digits, domicilio_digits, verifier_digits)
+    }
+
+    /// Where the voter is registered, see [`Domicilio`].
+    pub fn domicilio(&self) -> Domicilio {
+        Domicilio::from_code(self.domicilio_digits[0] * 10 + self.domicilio_digits[1])
+            .expect("Domicilio MUST be valid at this point")
+    }
+
+    /// UF where the voter is registered, or [`None`] if the voter is registered abroad.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::{TituloEleitor, UF};
+    ///
+    /// assert_eq!(TituloEleitor::parse_str("1023 8501 0671").unwrap().uf(), Some(UF::PR));
+    /// ```
+    pub fn uf(&self) -> Option<UF> {
+        self.domicilio().uf()
+    }
+}
+
+/// Parses a Título de Eleitor String to a [`TituloEleitor`], see [`TituloEleitor::parse_str`]
+/// for supported formats.
+impl FromStr for TituloEleitor {
+    type Err = TituloEleitorCreationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TituloEleitor::parse_str(s)
+    }
+}
+
+/// Parses a Título de Eleitor String to a [`TituloEleitor`], see [`TituloEleitor::parse_str`]
+/// for supported formats.
+impl TryFrom<&str> for TituloEleitor {
+    type Error = TituloEleitorCreationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        TituloEleitor::parse_str(value)
+    }
+}
+
+/// Converts the remainder of a weighted sum to a verifier digit, applying the special rule of
+/// São Paulo and Minas Gerais, which use `1` instead of `0` when the remainder is `0`.
+const fn rest_to_verifier_digit(sum: u16, domicilio_digits: [u8; 2]) -> u8 {
+    let rest = (sum % 11) as u8;
+    let sp_or_mg = domicilio_digits[0] == 0 && (domicilio_digits[1] == 1 || domicilio_digits[1] == 2);
+    match rest {
+        10 => 0,
+        0 if sp_or_mg => 1,
+        rest => rest,
+    }
+}
+
+/// Calculates both verifier digits of a Título de Eleitor.
+///
+/// The first verifier digit is the remainder of the division by 11 of the sum of the 8 sequential
+/// `digits` with weights `2..=9`, and the second one is the remainder of the sum of the
+/// `domicilio_digits` and the first verifier digit with weights `7`, `8` and `9`. A remainder of
+/// `10` produces the digit `0`, and for São Paulo (`01`) and Minas Gerais (`02`) a remainder of
+/// `0` produces the digit `1`.
+///
+/// # Example
+///
+/// ```
+/// use validbr::titulo_eleitor::calculate_verifier_digits;
+///
+/// assert_eq!(calculate_verifier_digits([1, 0, 2, 3, 8, 5, 0, 1], [0, 6]), (7, 1));
+/// ```
+pub const fn calculate_verifier_digits(digits: [u8; 8], domicilio_digits: [u8; 2]) -> (u8, u8) {
+    let mut sum: u16 = 0;
+    let mut pos = 0;
+    while pos < 8 {
+        sum += (digits[pos] as u16) * (pos as u16 + 2);
+        pos += 1;
+    }
+
+    let first_digit = rest_to_verifier_digit(sum, domicilio_digits);
+    let second_sum = (domicilio_digits[0] as u16) * 7 + (domicilio_digits[1] as u16) * 8 + (first_digit as u16) * 9;
+
+    (first_digit, rest_to_verifier_digit(second_sum, domicilio_digits))
+}
+
+/// ## Random Título de Eleitor Example
+///
+/// ```
+/// use validbr::TituloEleitor;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+/// let titulo: TituloEleitor = rng.gen();
+///
+/// let verifier = validbr::titulo_eleitor::calculate_verifier_digits(titulo.digits, titulo.domicilio_digits);
+///
+/// assert_eq!(verifier.0, titulo.verifier_digits[0]);
+/// assert_eq!(verifier.1, titulo.verifier_digits[1]);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<TituloEleitor> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TituloEleitor {
+        let code = rng.sample(Uniform::from(1..=EXTERIOR_CODE));
+        Domicilio::from_code(code).expect("Generated code MUST be valid").sample(rng)
+    }
+}
+
+/// ## Random Título de Eleitor of a Domicilio Example
+///
+/// ```
+/// use validbr::TituloEleitor;
+/// use validbr::titulo_eleitor::Domicilio;
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+///
+/// let titulo: TituloEleitor = rng.sample(Domicilio::Exterior);
+/// assert_eq!(titulo.domicilio(), Domicilio::Exterior);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<TituloEleitor> for Domicilio {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TituloEleitor {
+        let uniform_int = Uniform::from(0u8..=9u8);
+        let digits: [u8; 8] = core::array::from_fn(|_| rng.sample(uniform_int));
+        let code = self.code();
+        let domicilio_digits = [code / 10, code % 10];
+        let (first, second) = calculate_verifier_digits(digits, domicilio_digits);
+
+        TituloEleitor::new(digits, domicilio_digits, [first, second])
+            .expect("Generated TituloEleitor MUST be valid at this point")
+    }
+}
+
+/// ## Random Título de Eleitor of an UF Example
+///
+/// ```
+/// use validbr::{TituloEleitor, UF};
+/// use rand::Rng;
+/// let mut rng = rand::thread_rng();
+///
+/// let titulo: TituloEleitor = rng.sample(UF::MG);
+/// assert_eq!(titulo.uf(), Some(UF::MG));
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl Distribution<TituloEleitor> for UF {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TituloEleitor {
+        Domicilio::Uf(*self).sample(rng)
+    }
+}
@@ -0,0 +1,325 @@
+//     validbr - Brazilian registry validator, provides structures for representing CPF, CNPJ, RG, CNH, CEP and Credit Card Number!
+//
+//         The MIT License (MIT)
+//
+//      Copyright (c) Obliter Software (https://github.com/oblitersoftware/)
+//      Copyright (c) contributors
+//
+//      Permission is hereby granted, free of charge, to any person obtaining a copy
+//      of this software and associated documentation files (the "Software"), to deal
+//      in the Software without restriction, including without limitation the rights
+//      to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//      copies of the Software, and to permit persons to whom the Software is
+//      furnished to do so, subject to the following conditions:
+//
+//      The above copyright notice and this permission notice shall be included in
+//      all copies or substantial portions of the Software.
+//
+//      THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//      IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//      FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+//      AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//      LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//      OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//      THE SOFTWARE.
+
+use crate::{Municipio, UF};
+use crate::error::{impl_error, write_message, Language, LocalizedError};
+use crate::parse::eq_ignore_accents;
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Formatter;
+use core::str::FromStr;
+
+/// Parses the two letters abbreviation or the full name of an [`UF`], ignoring case and accents.
+///
+/// # Example
+///
+/// ```
+/// use validbr::UF;
+///
+/// assert_eq!("SP".parse::<UF>(), Ok(UF::SP));
+/// assert_eq!("rj".parse::<UF>(), Ok(UF::RJ));
+/// assert_eq!("São Paulo".parse::<UF>(), Ok(UF::SP));
+/// assert_eq!("sao paulo".parse::<UF>(), Ok(UF::SP));
+/// assert!("XX".parse::<UF>().is_err());
+/// ```
+impl FromStr for UF {
+    type Err = InvalidUf;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        UF::ALL
+            .iter()
+            .find(|uf| s.eq_ignore_ascii_case(uf.abbreviation()) || eq_ignore_accents(s, uf.name()))
+            .copied()
+            .ok_or(InvalidUf)
+    }
+}
+
+/// Resolves the [`UF`] of its IBGE code.
+///
+/// # Example
+///
+/// ```
+/// use validbr::UF;
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(UF::try_from(35), Ok(UF::SP));
+/// assert!(UF::try_from(99).is_err());
+/// ```
+impl TryFrom<u8> for UF {
+    type Error = InvalidUfCode;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        UF::ALL
+            .iter()
+            .find(|uf| uf.ibge_code() == code)
+            .copied()
+            .ok_or(InvalidUfCode(code))
+    }
+}
+
+/// IBGE code of the [`UF`].
+impl From<UF> for u8 {
+    fn from(uf: UF) -> Self {
+        uf.ibge_code()
+    }
+}
+
+impl UF {
+    /// All the UFs, in the order they are declared.
+    pub const ALL: [UF; 27] = [
+        UF::AC, UF::AL, UF::AP, UF::AM, UF::BA, UF::CE, UF::DF, UF::ES, UF::GO,
+        UF::MA, UF::MT, UF::MS, UF::MG, UF::PA, UF::PB, UF::PR, UF::PE, UF::PI,
+        UF::RJ, UF::RN, UF::RS, UF::RO, UF::RR, UF::SC, UF::SP, UF::SE, UF::TO,
+    ];
+
+    /// Two letters abbreviation of the UF, for example: `SP`.
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            UF::AC => "AC",
+            UF::AL => "AL",
+            UF::AP => "AP",
+            UF::AM => "AM",
+            UF::BA => "BA",
+            UF::CE => "CE",
+            UF::DF => "DF",
+            UF::ES => "ES",
+            UF::GO => "GO",
+            UF::MA => "MA",
+            UF::MT => "MT",
+            UF::MS => "MS",
+            UF::MG => "MG",
+            UF::PA => "PA",
+            UF::PB => "PB",
+            UF::PR => "PR",
+            UF::PE => "PE",
+            UF::PI => "PI",
+            UF::RJ => "RJ",
+            UF::RN => "RN",
+            UF::RS => "RS",
+            UF::RO => "RO",
+            UF::RR => "RR",
+            UF::SC => "SC",
+            UF::SP => "SP",
+            UF::SE => "SE",
+            UF::TO => "TO",
+        }
+    }
+
+    /// Full name of the UF, for example: `São Paulo`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::UF;
+    ///
+    /// assert_eq!(UF::DF.name(), "Distrito Federal");
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            UF::AC => "Acre",
+            UF::AL => "Alagoas",
+            UF::AP => "Amapá",
+            UF::AM => "Amazonas",
+            UF::BA => "Bahia",
+            UF::CE => "Ceará",
+            UF::DF => "Distrito Federal",
+            UF::ES => "Espírito Santo",
+            UF::GO => "Goiás",
+            UF::MA => "Maranhão",
+            UF::MT => "Mato Grosso",
+            UF::MS => "Mato Grosso do Sul",
+            UF::MG => "Minas Gerais",
+            UF::PA => "Pará",
+            UF::PB => "Paraíba",
+            UF::PR => "Paraná",
+            UF::PE => "Pernambuco",
+            UF::PI => "Piauí",
+            UF::RJ => "Rio de Janeiro",
+            UF::RN => "Rio Grande do Norte",
+            UF::RS => "Rio Grande do Sul",
+            UF::RO => "Rondônia",
+            UF::RR => "Roraima",
+            UF::SC => "Santa Catarina",
+            UF::SP => "São Paulo",
+            UF::SE => "Sergipe",
+            UF::TO => "Tocantins",
+        }
+    }
+
+    /// IBGE code of the UF, which is also the prefix of the IBGE codes of its Municipios.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::UF;
+    ///
+    /// assert_eq!(UF::SP.ibge_code(), 35);
+    /// ```
+    pub const fn ibge_code(&self) -> u8 {
+        match self {
+            UF::RO => 11,
+            UF::AC => 12,
+            UF::AM => 13,
+            UF::RR => 14,
+            UF::PA => 15,
+            UF::AP => 16,
+            UF::TO => 17,
+            UF::MA => 21,
+            UF::PI => 22,
+            UF::CE => 23,
+            UF::RN => 24,
+            UF::PB => 25,
+            UF::PE => 26,
+            UF::AL => 27,
+            UF::SE => 28,
+            UF::BA => 29,
+            UF::MG => 31,
+            UF::ES => 32,
+            UF::RJ => 33,
+            UF::SP => 35,
+            UF::PR => 41,
+            UF::SC => 42,
+            UF::RS => 43,
+            UF::MS => 50,
+            UF::MT => 51,
+            UF::GO => 52,
+            UF::DF => 53,
+        }
+    }
+
+    /// Macro-region the UF belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::UF;
+    /// use validbr::uf::Regiao;
+    ///
+    /// assert_eq!(UF::SP.regiao(), Regiao::Sudeste);
+    /// ```
+    pub const fn regiao(&self) -> Regiao {
+        match self.ibge_code() / 10 {
+            1 => Regiao::Norte,
+            2 => Regiao::Nordeste,
+            3 => Regiao::Sudeste,
+            4 => Regiao::Sul,
+            _ => Regiao::CentroOeste,
+        }
+    }
+
+    /// Capital of the UF.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::UF;
+    ///
+    /// assert_eq!(UF::BA.capital().name, "Salvador");
+    /// ```
+    pub fn capital(&self) -> &'static Municipio {
+        Municipio::of(self)
+            .find(|municipio| municipio.capital)
+            .expect("Every UF MUST have its capital in the Municipio table")
+    }
+}
+
+/// Brazilian macro-regions, as defined by IBGE.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy)]
+pub enum Regiao {
+    Norte,
+    Nordeste,
+    CentroOeste,
+    Sudeste,
+    Sul,
+}
+
+impl Regiao {
+    /// All the macro-regions.
+    pub const ALL: [Regiao; 5] = [Regiao::Norte, Regiao::Nordeste, Regiao::CentroOeste, Regiao::Sudeste, Regiao::Sul];
+
+    /// Name of the macro-region, for example: `Centro-Oeste`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Regiao::Norte => "Norte",
+            Regiao::Nordeste => "Nordeste",
+            Regiao::CentroOeste => "Centro-Oeste",
+            Regiao::Sudeste => "Sudeste",
+            Regiao::Sul => "Sul",
+        }
+    }
+
+    /// Iterates over the UFs of the macro-region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use validbr::UF;
+    /// use validbr::uf::Regiao;
+    ///
+    /// let ufs: Vec<UF> = Regiao::Sul.ufs().collect();
+    /// assert_eq!(ufs, vec![UF::PR, UF::RS, UF::SC]);
+    /// ```
+    pub fn ufs(&self) -> impl Iterator<Item = UF> {
+        let regiao = *self;
+        UF::ALL.iter().copied().filter(move |uf| uf.regiao() == regiao)
+    }
+}
+
+impl fmt::Display for Regiao {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error returned when a string is not a known [`UF`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct InvalidUf;
+
+impl LocalizedError for InvalidUf {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        write_message(
+            f,
+            language,
+            "invalid UF, expected the abbreviation or the name of a Brazilian state",
+            "UF inválida, esperada a sigla ou o nome de um estado brasileiro",
+        )
+    }
+}
+
+/// Error returned when a number is not the IBGE code of an [`UF`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct InvalidUfCode(pub u8);
+
+impl LocalizedError for InvalidUfCode {
+    fn fmt_localized(&self, f: &mut Formatter<'_>, language: Language) -> fmt::Result {
+        match language {
+            Language::English => write!(f, "{} is not the IBGE code of an UF", self.0),
+            Language::BrazilianPortuguese => write!(f, "{} não é o código IBGE de uma UF", self.0),
+        }
+    }
+}
+
+impl_error!(InvalidUf, InvalidUfCode);